@@ -8,25 +8,251 @@
     rustdoc::all
 )]
 
-use bookmark_data::FileData;
-use bookmark_ui_util::{tabs::Tabs, IteratorWidgetExt};
+use bookmark_data::{BookmarkData, CategoryData, CategoryMatch, FileData, Inheritance};
+use bookmark_ui_util::{
+    bookmark_row::BookmarkRow,
+    color::ContrastLevel,
+    color_scheme::{self, ColorScheme},
+    custom::{self, CustomTheme},
+    icon::Icon,
+    modal::Modal,
+    table::{Sort, Table, TableColumn},
+    tabs::Tabs,
+    text_button::{self, TextButton},
+    theme::{self, Var},
+    toast::{Toast, Toasts},
+    virtual_list::VirtualList,
+    IteratorWidgetExt, Renderer, Theme,
+};
+use bookmark_util::AnyWithExt;
 use derive_more::From;
 use iced::{
-    executor, theme,
-    widget::{button, container, scrollable, text},
-    Command, Element, Length,
+    executor, subscription, time,
+    widget::{button, container, pick_list, scrollable, text, text_input, Column, Row},
+    window, Alignment, Command, Element, Event, Length, Subscription,
+};
+use log_buffer::{LogBuffer, LogFilter};
+use settings::{Settings, ThemeMode, WindowSize};
+use std::{
+    fmt::Display,
+    path::PathBuf,
+    time::{Duration, SystemTime},
 };
-use std::path::PathBuf;
 use tap::Pipe;
+use tracing::Level;
+use workspace::{Tab, TabKind};
 
 pub use iced::Application;
 
+pub mod log_buffer;
+pub mod settings;
+pub mod workspace;
+
 /// Application class.
 #[derive(Debug, Default)]
 pub struct App {
     data: Option<FileData>,
-    tabs: Vec<String>,
+    data_path: Option<PathBuf>,
+    tabs: Vec<Tab>,
     selected_tab: usize,
+    tab_sidebar: bool,
+    armed: Option<(Deletion, u64)>,
+    arm_count: u64,
+    viewport_width: u32,
+    viewport_height: u32,
+    bookmark_view: BookmarkView,
+    bookmark_sort: Option<Sort>,
+    column_width: [u16; 6],
+    categories: Vec<CategoryCount>,
+    uncategorized: Vec<usize>,
+    settings: Settings,
+    settings_load_failed: bool,
+    theme_mode: ThemeMode,
+    color_scheme: ColorScheme,
+    custom_theme: Option<Theme>,
+    theme_file: Option<PathBuf>,
+    errors: Vec<String>,
+    file_dialog: Option<FileDialog>,
+    toasts: Toasts,
+    log: LogBuffer,
+}
+
+/// Height of rows in the bookmark table.
+const BOOKMARK_ROW_HEIGHT: u16 = 30;
+
+/// How often the log tab is refreshed while it is shown.
+const LOG_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Height of rows in the bookmark list.
+const BOOKMARK_LIST_ROW_HEIGHT: u16 = 64;
+
+/// Height of rows in the log tab.
+const LOG_ROW_HEIGHT: u16 = 24;
+
+/// How bookmarks are shown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BookmarkView {
+    /// Show bookmarks as a list of rows with their info, url and tags.
+    #[default]
+    List,
+    /// Show bookmarks as a table with sortable columns.
+    Table,
+}
+
+impl Display for BookmarkView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BookmarkView::List => write!(f, "list"),
+            BookmarkView::Table => write!(f, "table"),
+        }
+    }
+}
+
+/// Something that can be deleted from file data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Deletion {
+    /// A bookmark by its uuid.
+    Bookmark(uuid::Uuid),
+    /// A category by its path of indices, see [`FileData::category_mut`].
+    Category(Vec<usize>),
+}
+
+/// A category chosen by its path of indices, see [`FileData::category_mut`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryChoice {
+    path: Vec<usize>,
+    name: String,
+}
+
+impl CategoryChoice {
+    /// Get choices for all categories of file data a bookmark can be added to by its url, which
+    /// are the top level categories and independent subcategories, since a filtering subcategory
+    /// only considers the bookmarks of its parent.
+    fn all(file_data: &FileData) -> Vec<Self> {
+        fn push(
+            choices: &mut Vec<CategoryChoice>,
+            path: &mut Vec<usize>,
+            prefix: &str,
+            category: &[CategoryData],
+        ) {
+            for (index, category) in category.iter().enumerate() {
+                path.push(index);
+                let name = if prefix.is_empty() {
+                    category.name.clone()
+                } else {
+                    format!("{prefix}/{}", category.name)
+                };
+                if prefix.is_empty() || category.inheritance == Inheritance::Independent {
+                    choices.push(CategoryChoice {
+                        path: path.clone(),
+                        name: name.clone(),
+                    });
+                }
+                push(choices, path, &name, &category.subcategory);
+                path.pop();
+            }
+        }
+
+        let mut choices = Vec::new();
+        push(&mut choices, &mut Vec::new(), "", &file_data.category);
+        choices
+    }
+}
+
+impl Display for CategoryChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// Bookmark counts of a category and its subcategories, kept from [`FileData::categorize`] so
+/// categories are only evaluated when the file data changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CategoryCount {
+    name: String,
+    inheritance: Inheritance,
+    bookmarks: usize,
+    unsorted: usize,
+    subcategory: Vec<CategoryCount>,
+}
+
+impl From<&CategoryMatch<'_>> for CategoryCount {
+    fn from(value: &CategoryMatch<'_>) -> Self {
+        Self {
+            name: value.category.name.clone(),
+            inheritance: value.category.inheritance,
+            bookmarks: value.bookmark.len(),
+            unsorted: value.unsorted.len(),
+            subcategory: value.subcategory.iter().map(Self::from).collect(),
+        }
+    }
+}
+
+/// What the file chosen in the file dialog is used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilePurpose {
+    /// Load an existing file.
+    Open,
+    /// Create a new empty file.
+    New,
+}
+
+/// State of the file dialog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FileDialog {
+    purpose: FilePurpose,
+    path: String,
+}
+
+impl FileDialog {
+    /// View the dialog for entering the path of a file to open or create, or picking it with the
+    /// native file picker of the platform where there is one.
+    fn view(&self) -> Element<'_, Message, Renderer> {
+        let (title, submit) = match self.purpose {
+            FilePurpose::Open => ("open file", "open"),
+            FilePurpose::New => ("new collection", "create"),
+        };
+        Column::new()
+            .push(text(title).size(24))
+            .push(
+                // Changes carry the new path, submitting carries none.
+                text_input("path", &self.path, Some)
+                    .on_submit(None)
+                    .padding(3)
+                    .pipe(Element::from)
+                    .map(|input: Option<String>| {
+                        input.map_or(Message::SubmitFileDialog, Message::SetFilePath)
+                    }),
+            )
+            .push(
+                Row::new()
+                    .push(TextButton::new_with_on_press(&submit, || {
+                        Message::SubmitFileDialog
+                    }))
+                    .push(
+                        TextButton::new_with_on_press(&"browse…", || Message::BrowseFile)
+                            .style(text_button::Style::Secondary),
+                    )
+                    .push(
+                        TextButton::new_with_on_press(&"cancel", || Message::CloseFileDialog)
+                            .style(text_button::Style::Secondary),
+                    )
+                    .spacing(6),
+            )
+            .spacing(6)
+            .width(Length::Units(400))
+            .into()
+    }
+}
+
+/// A recently opened file, chosen to open it again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecentFile(PathBuf);
+
+impl Display for RecentFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.display())
+    }
 }
 
 /// Flags used to set initial state of [App].
@@ -34,25 +260,142 @@ pub struct App {
 pub struct Flags {
     /// Files to load on startup.
     pub files: Vec<PathBuf>,
+    /// Theme file to load and watch for changes, used instead of the theme in settings.
+    pub theme: Option<PathBuf>,
+    /// Settings loaded on startup.
+    pub settings: Settings,
+    /// Why the settings file could not be loaded, if it could not. Settings are then not saved,
+    /// so the file is not replaced before it is fixed.
+    pub settings_error: Option<String>,
+    /// Buffer of tracing events shown in the log tab.
+    pub log: LogBuffer,
 }
 
 /// Top Message class used by [App].
 #[derive(Debug, From)]
 pub enum Message {
-    /// Signal a file has been loaded.
-    FileLoaded(bookmark_data::Result<FileData>),
+    /// Signal the file at a path has been loaded.
+    #[from(ignore)]
+    FileLoaded(PathBuf, bookmark_data::Result<FileData>),
+    /// Signal an empty file has been created at a path.
+    #[from(ignore)]
+    FileCreated(PathBuf, bookmark_data::Result<FileData>),
     /// Signal a file should be loaded.
     #[from(ignore)]
     LoadFile(PathBuf),
+    /// Show the dialog for choosing a file to open or create.
+    #[from(ignore)]
+    OpenFileDialog(FilePurpose),
+    /// Set the path entered in the file dialog.
+    #[from(ignore)]
+    SetFilePath(String),
+    /// Open or create the file at the path entered in the file dialog.
+    #[from(ignore)]
+    SubmitFileDialog,
+    /// Choose the file to open or create with the native file picker of the platform.
+    #[from(ignore)]
+    BrowseFile,
+    /// Signal the native file picker has been closed, with the chosen file if there is one.
+    #[from(ignore)]
+    FileBrowsed(Option<PathBuf>),
+    /// Close the file dialog.
+    #[from(ignore)]
+    CloseFileDialog,
     /// Signal a bookmark should be opened.
     #[from(ignore)]
     OpenBookmark(uuid::Uuid),
-    /// Add a blank tab.
+    /// Open a tab of some kind.
     #[from(ignore)]
-    AddTab(String),
-    /// Select a blank tab.
+    AddTab(TabKind),
+    /// Select a tab.
     #[from(ignore)]
     SelTab(usize),
+    /// Close a tab.
+    #[from(ignore)]
+    CloseTab(usize),
+    /// Move a tab to a new index.
+    #[from(ignore)]
+    MoveTab(usize, usize),
+    /// Choose if tabs are shown in a sidebar instead of above content.
+    #[from(ignore)]
+    SetTabSidebar(bool),
+    /// Choose the category uncategorized bookmarks in the selected tab are sorted into.
+    #[from(ignore)]
+    TriageCategory(CategoryChoice),
+    /// Scroll the uncategorized bookmarks of the selected tab to a relative offset.
+    #[from(ignore)]
+    TriageScroll(f32),
+    /// Add a bookmark to the category chosen in the selected tab by a `whole` rule.
+    #[from(ignore)]
+    Categorize(uuid::Uuid),
+    /// Arm the delete button of something, it is deleted when pressed again.
+    #[from(ignore)]
+    ArmDelete(Deletion),
+    /// Disarm a delete button if it is still armed by the given arming.
+    #[from(ignore)]
+    Disarm(u64),
+    /// Delete something from file data.
+    #[from(ignore)]
+    Delete(Deletion),
+    /// Signal the bookmark list of the selected tab has been scrolled to a relative offset.
+    #[from(ignore)]
+    BookmarkScroll(f32),
+    /// Set the text bookmarks shown in the selected tab contain.
+    #[from(ignore)]
+    SearchBookmarks(String),
+    /// Choose how bookmarks are shown.
+    #[from(ignore)]
+    SetBookmarkView(BookmarkView),
+    /// Sort the bookmark table.
+    #[from(ignore)]
+    SortBookmarks(Sort),
+    /// Resize a column of the bookmark table.
+    #[from(ignore)]
+    ResizeColumn(usize, u16),
+    /// Change the theme in use.
+    #[from(ignore)]
+    SetThemeMode(ThemeMode),
+    /// Signal the theme file has been loaded.
+    ThemeLoaded(custom::Result<CustomTheme>),
+    /// Signal the desktop color scheme preference has been read or changed.
+    #[from(ignore)]
+    ColorSchemeChanged(ColorScheme),
+    /// Signal the loaded file data has been saved after it was changed.
+    #[from(ignore)]
+    DataSaved(bookmark_data::Result),
+    /// Signal settings have been saved.
+    SettingsSaved(settings::Result),
+    /// Signal settings have been saved after the session changed, such as the open tabs or
+    /// recently opened files.
+    #[from(ignore)]
+    SessionSaved(settings::Result),
+    /// Signal settings have been saved after closing the window was requested.
+    #[from(ignore)]
+    SavedOnExit(settings::Result),
+    /// Set the most verbose level shown in the selected log tab.
+    #[from(ignore)]
+    SetLogLevel(Level),
+    /// Set the text entries shown in the selected log tab contain.
+    #[from(ignore)]
+    SearchLog(String),
+    /// Signal the entries of the selected log tab have been scrolled to a relative offset.
+    #[from(ignore)]
+    LogScroll(f32),
+    /// Refresh the log tab.
+    #[from(ignore)]
+    LogTick,
+    /// Dismiss a toast by its id, when it expires or is closed.
+    #[from(ignore)]
+    DismissToast(u64),
+    /// Dismiss the error currently shown.
+    #[from(ignore)]
+    DismissError,
+    /// Signal the window has been resized to a new width and height.
+    #[from(ignore)]
+    WindowResized(u32, u32),
+    /// Signal closing the window has been requested.
+    #[from(ignore)]
+    CloseRequested,
 }
 
 impl Application for App {
@@ -60,25 +403,33 @@ impl Application for App {
 
     type Message = Message;
 
-    type Theme = iced::Theme;
+    type Theme = Theme;
 
     type Flags = Flags;
 
     fn new(flags: Self::Flags) -> (Self, Command<Self::Message>) {
         (
             Self {
-                tabs: [
-                    "bookmarks",
-                    "edit",
-                    "log",
-                    "one",
-                    "two",
-                    "three",
-                    "four",
-                    "fiftyseven",
-                ]
-                .map(String::from)
-                .into(),
+                tabs: flags.settings.tabs.iter().copied().map(Tab::from).collect(),
+                viewport_width: flags.settings.window.width,
+                viewport_height: flags.settings.window.height,
+                column_width: [200, 400, 150, 180, 180, 120],
+                theme_mode: if flags.theme.is_some() {
+                    ThemeMode::Custom
+                } else {
+                    flags.settings.theme
+                },
+                settings: flags.settings,
+                settings_load_failed: flags.settings_error.is_some(),
+                errors: flags
+                    .settings_error
+                    .map(|err| {
+                        format!("failed to load settings, changes are not saved until fixed: {err}")
+                    })
+                    .into_iter()
+                    .collect(),
+                theme_file: flags.theme,
+                log: flags.log,
                 ..Self::default()
             },
             if flags.files.is_empty() {
@@ -95,20 +446,99 @@ impl Application for App {
     }
 
     fn title(&self) -> String {
-        "Application".into()
+        self.data_path.as_ref().map_or_else(
+            || "Application".into(),
+            |path| format!("{} - Application", path.display()),
+        )
     }
 
+    #[allow(clippy::too_many_lines)]
     fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
         match message {
-            Message::FileLoaded(Ok(file_data)) => {
-                self.data = Some(file_data);
+            Message::FileLoaded(path, Ok(file_data)) => {
+                let toast =
+                    Toast::success(format!("loaded {} bookmarks", file_data.bookmark.len()));
+                self.use_data(path, file_data, toast)
+            }
+            Message::FileLoaded(_, Err(err)) => {
+                tracing::error!("failed to load file data: {err}");
+                self.toasts.push(
+                    Toast::error(format!("failed to load file data: {err}")),
+                    Message::DismissToast,
+                )
+            }
+            Message::FileCreated(path, Ok(file_data)) => {
+                let toast = Toast::success(format!("created {}", path.display()));
+                self.use_data(path, file_data, toast)
+            }
+            Message::FileCreated(path, Err(err)) => {
+                tracing::error!("failed to create {}: {err}", path.display());
+                self.toasts.push(
+                    Toast::error(format!("failed to create {}: {err}", path.display())),
+                    Message::DismissToast,
+                )
+            }
+            Message::DataSaved(Err(err)) => {
+                tracing::error!("failed to save file data: {err}");
+                self.toasts.push(
+                    Toast::error(format!("failed to save file data: {err}")),
+                    Message::DismissToast,
+                )
+            }
+            Message::LoadFile(path) => {
+                Command::perform(FileData::load(path.clone()), move |result| {
+                    Message::FileLoaded(path, result)
+                })
+            }
+            Message::OpenFileDialog(purpose) => {
+                self.file_dialog = Some(FileDialog {
+                    purpose,
+                    path: String::new(),
+                });
                 Command::none()
             }
-            Message::FileLoaded(Err(err)) => {
-                eprintln!("failed to load file data: {err}");
+            Message::SetFilePath(path) => {
+                if let Some(file_dialog) = &mut self.file_dialog {
+                    file_dialog.path = path;
+                }
+                Command::none()
+            }
+            Message::SubmitFileDialog => match self.file_dialog.take() {
+                Some(FileDialog { purpose, path }) if !path.is_empty() => {
+                    self.use_file(purpose, PathBuf::from(path))
+                }
+                file_dialog => {
+                    self.file_dialog = file_dialog;
+                    Command::none()
+                }
+            },
+            Message::BrowseFile => match &self.file_dialog {
+                Some(FileDialog {
+                    purpose: FilePurpose::Open,
+                    ..
+                }) => Command::perform(pick_file(), Message::FileBrowsed),
+                Some(FileDialog {
+                    purpose: FilePurpose::New,
+                    ..
+                }) => Command::perform(pick_new_file(), Message::FileBrowsed),
+                None => Command::none(),
+            },
+            Message::FileBrowsed(Some(path)) => match self.file_dialog.take() {
+                Some(FileDialog {
+                    purpose: FilePurpose::Open,
+                    ..
+                }) => self.update(Message::LoadFile(path)),
+                // The native file picker already asked to confirm replacing an existing file.
+                Some(FileDialog {
+                    purpose: FilePurpose::New,
+                    ..
+                }) => create_file(path, true),
+                None => Command::none(),
+            },
+            Message::CloseFileDialog => {
+                self.file_dialog = None;
                 Command::none()
             }
-            Message::LoadFile(file) => Command::perform(FileData::load(file), Message::FileLoaded),
             Message::OpenBookmark(id) => {
                 if let Some(ref file_data) = self.data {
                     if let Some(bookmark) = file_data
@@ -116,52 +546,938 @@ impl Application for App {
                         .iter()
                         .find(|bookmark| bookmark.uuid == id)
                     {
-                        println!("Open \"{}\"", bookmark.url);
+                        tracing::info!(url = bookmark.url, "opening bookmark");
+                        let opened = match &self.settings.browser {
+                            Some(browser) => open::with(&bookmark.url, browser),
+                            None => open::that(&bookmark.url),
+                        };
+                        if let Err(err) = opened {
+                            tracing::warn!(url = bookmark.url, "failed to open bookmark: {err}");
+                            return self.toasts.push(
+                                Toast::error(format!("failed to open bookmark: {err}")),
+                                Message::DismissToast,
+                            );
+                        }
                     } else {
-                        println!("could not find bookmark");
+                        tracing::warn!(%id, "could not find bookmark to open");
                     }
                 }
                 Command::none()
             }
-            Message::AddTab(name) => {
-                self.tabs.push(name);
-                Command::none()
+            Message::AddTab(kind) => {
+                let mut tab = Tab::from(kind);
+                tab.filter(self.data.as_ref(), self.bookmark_sort);
+                self.tabs.push(tab);
+                self.selected_tab = self.tabs.len() - 1;
+                Command::batch([self.snap_selected_tab(), self.save_session()])
             }
             Message::SelTab(tab) => {
                 self.selected_tab = tab;
+                self.snap_selected_tab()
+            }
+            Message::CloseTab(tab) => {
+                if tab < self.tabs.len() {
+                    self.tabs.remove(tab);
+                    if tab < self.selected_tab {
+                        self.selected_tab -= 1;
+                    }
+                    self.selected_tab = self.selected_tab.min(self.tabs.len().saturating_sub(1));
+                }
+                Command::batch([self.snap_selected_tab(), self.save_session()])
+            }
+            Message::MoveTab(from, to) => {
+                if from < self.tabs.len() && to < self.tabs.len() {
+                    let tab = self.tabs.remove(from);
+                    self.tabs.insert(to, tab);
+                    self.selected_tab = if self.selected_tab == from {
+                        to
+                    } else if from < self.selected_tab && to >= self.selected_tab {
+                        self.selected_tab - 1
+                    } else if from > self.selected_tab && to <= self.selected_tab {
+                        self.selected_tab + 1
+                    } else {
+                        self.selected_tab
+                    };
+                }
+                Command::batch([self.snap_selected_tab(), self.save_session()])
+            }
+            Message::SetTabSidebar(tab_sidebar) => {
+                self.tab_sidebar = tab_sidebar;
+                Command::none()
+            }
+            Message::BookmarkScroll(new_offset) => {
+                if let Some(Tab::Bookmarks { offset, .. }) = self.tabs.get_mut(self.selected_tab) {
+                    *offset = new_offset;
+                }
+                Command::none()
+            }
+            Message::SearchBookmarks(new_search) => {
+                if let Some(tab) = self.tabs.get_mut(self.selected_tab) {
+                    if let Tab::Bookmarks { search, offset, .. } = tab {
+                        *search = new_search;
+                        *offset = 0.0;
+                    }
+                    tab.filter(self.data.as_ref(), self.bookmark_sort);
+                }
+                self.snap_selected_tab()
+            }
+            Message::SetBookmarkView(view) => {
+                self.bookmark_view = view;
+                for tab in &mut self.tabs {
+                    if let Tab::Bookmarks { offset, .. } = tab {
+                        *offset = 0.0;
+                    }
+                }
+                self.snap_selected_tab()
+            }
+            Message::SortBookmarks(sort) => {
+                self.bookmark_sort = Some(sort);
+                self.filter_tabs();
+                Command::none()
+            }
+            Message::ResizeColumn(column, width) => {
+                if let Some(column_width) = self.column_width.get_mut(column) {
+                    *column_width = width;
+                }
+                Command::none()
+            }
+            // The custom theme only exists while a theme file is given, so it is not persisted.
+            Message::SetThemeMode(ThemeMode::Custom) => {
+                self.theme_mode = ThemeMode::Custom;
+                Command::none()
+            }
+            Message::SetThemeMode(mode) => {
+                self.theme_mode = mode;
+                self.settings.theme = mode;
+                self.save_settings(Message::SettingsSaved)
+                    .unwrap_or_else(Command::none)
+            }
+            Message::ThemeLoaded(Ok(custom_theme)) => {
+                let custom_theme = Theme::from(custom_theme);
+                if let Err(issues) = custom_theme.theme_palette().validate(ContrastLevel::AA) {
+                    for issue in issues {
+                        tracing::warn!("theme {custom_theme} has low contrast: {issue}");
+                    }
+                }
+                self.custom_theme = Some(custom_theme);
+                Command::none()
+            }
+            Message::ThemeLoaded(Err(err)) => {
+                tracing::error!("failed to load theme: {err}");
+                self.errors.push(format!("failed to load theme: {err}"));
+                Command::none()
+            }
+            Message::ColorSchemeChanged(color_scheme) => {
+                self.color_scheme = color_scheme;
+                Command::none()
+            }
+            Message::SettingsSaved(Err(err)) => {
+                tracing::error!("failed to save settings: {err}");
+                self.toasts.push(
+                    Toast::error(format!("failed to save settings: {err}")),
+                    Message::DismissToast,
+                )
+            }
+            Message::FileBrowsed(None)
+            | Message::DataSaved(Ok(()))
+            | Message::SettingsSaved(Ok(()))
+            | Message::SessionSaved(Ok(()))
+            | Message::LogTick => Command::none(),
+            Message::SessionSaved(Err(err)) => {
+                tracing::error!("failed to save session: {err}");
+                self.toasts.push(
+                    Toast::error(format!("failed to save session: {err}")),
+                    Message::DismissToast,
+                )
+            }
+            Message::SavedOnExit(result) => {
+                if let Err(err) = result {
+                    tracing::error!("failed to save settings on exit: {err}");
+                }
+                window::close()
+            }
+            Message::SetLogLevel(level) => {
+                if let Some(Tab::Log { filter, offset, .. }) = self.tabs.get_mut(self.selected_tab)
+                {
+                    filter.level = level;
+                    *offset = 0.0;
+                }
+                self.snap_selected_tab()
+            }
+            Message::SearchLog(search) => {
+                if let Some(Tab::Log { filter, offset, .. }) = self.tabs.get_mut(self.selected_tab)
+                {
+                    filter.search = search;
+                    *offset = 0.0;
+                }
+                self.snap_selected_tab()
+            }
+            Message::LogScroll(new_offset) => {
+                if let Some(Tab::Log { offset, .. }) = self.tabs.get_mut(self.selected_tab) {
+                    *offset = new_offset;
+                }
+                Command::none()
+            }
+            Message::DismissToast(id) => {
+                self.toasts.dismiss(id);
+                Command::none()
+            }
+            Message::DismissError => {
+                if !self.errors.is_empty() {
+                    self.errors.remove(0);
+                }
+                Command::none()
+            }
+            Message::WindowResized(width, height) => {
+                self.viewport_width = width;
+                self.viewport_height = height;
+                self.settings.window = WindowSize { width, height };
+                Command::none()
+            }
+            Message::CloseRequested => self
+                .save_settings(Message::SavedOnExit)
+                .unwrap_or_else(window::close),
+            Message::TriageCategory(choice) => {
+                if let Some(Tab::Uncategorized { category, .. }) =
+                    self.tabs.get_mut(self.selected_tab)
+                {
+                    *category = Some(choice);
+                }
+                Command::none()
+            }
+            Message::TriageScroll(new_offset) => {
+                if let Some(Tab::Uncategorized { offset, .. }) =
+                    self.tabs.get_mut(self.selected_tab)
+                {
+                    *offset = new_offset;
+                }
+                Command::none()
+            }
+            Message::ArmDelete(target) => {
+                self.arm_count += 1;
+                self.armed = Some((target, self.arm_count));
+                text_button::disarm_after(Message::Disarm(self.arm_count))
+            }
+            Message::Disarm(count) => {
+                if matches!(self.armed, Some((_, armed)) if armed == count) {
+                    self.armed = None;
+                }
                 Command::none()
             }
+            Message::Delete(target) => {
+                self.armed = None;
+                if let Some(file_data) = &mut self.data {
+                    match target {
+                        Deletion::Bookmark(uuid) => {
+                            file_data.remove_bookmark(uuid);
+                            // Indices of other bookmarks may have shifted.
+                            self.filter_tabs();
+                        }
+                        Deletion::Category(path) => {
+                            file_data.remove_category(&path);
+                            // Paths of other categories may have shifted.
+                            self.clear_category_choices();
+                        }
+                    }
+                }
+                self.categorize();
+                self.save_data()
+            }
+            Message::Categorize(id) => {
+                let Some(Tab::Uncategorized {
+                    category: Some(choice),
+                    ..
+                }) = self.tabs.get(self.selected_tab)
+                else {
+                    return Command::none();
+                };
+                let Some(file_data) = &mut self.data else {
+                    return Command::none();
+                };
+                let Some(url) = file_data
+                    .bookmark
+                    .iter()
+                    .find(|bookmark| bookmark.uuid == id)
+                    .map(|bookmark| bookmark.url.clone())
+                else {
+                    return Command::none();
+                };
+                let Some(category) = file_data.category_mut(&choice.path) else {
+                    return Command::none();
+                };
+                let save = if category.identifier.whole.contains(&url) {
+                    Command::none()
+                } else {
+                    tracing::info!(url, category = category.name, "added bookmark to category");
+                    category.identifier.whole.push(url.clone());
+                    self.categorize();
+                    self.save_data()
+                };
+                if self.is_uncategorized(id) {
+                    Command::batch([
+                        save,
+                        self.toasts.push(
+                            Toast::error(format!("{url} is still not in any category")),
+                            Message::DismissToast,
+                        ),
+                    ])
+                } else {
+                    save
+                }
+            }
         }
     }
 
-    fn view(&self) -> iced::Element<Self::Message> {
-        let Some(ref file_data) = self.data else {
-            return text("no data loaded").pipe(container).width(Length::Fill).height(Length::Fill).center_x().center_y().into();
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::batch([
+            subscription::events_with(|event, _status| match event {
+                // Minimized windows report a size of zero, which should not be restored.
+                Event::Window(window::Event::Resized { width, height })
+                    if width > 0 && height > 0 =>
+                {
+                    Some(Message::WindowResized(width, height))
+                }
+                Event::Window(window::Event::CloseRequested) => Some(Message::CloseRequested),
+                _ => None,
+            }),
+            color_scheme::watch().map(Message::ColorSchemeChanged),
+            if matches!(self.tabs.get(self.selected_tab), Some(Tab::Log { .. })) {
+                time::every(LOG_REFRESH_INTERVAL).map(|_| Message::LogTick)
+            } else {
+                Subscription::none()
+            },
+            self.theme_file
+                .clone()
+                .map_or_else(Subscription::none, |path| {
+                    custom::watch(path).map(Message::ThemeLoaded)
+                }),
+        ])
+    }
+
+    fn view(&self) -> Element<'_, Self::Message, Renderer> {
+        // Errors are shown before the file dialog, which stays open behind them.
+        let error_shown = !self.errors.is_empty();
+        let dialog = match (self.errors.first(), &self.file_dialog) {
+            (Some(error), _) => Some(self.error_dialog(error)),
+            (None, Some(file_dialog)) => Some(file_dialog.view()),
+            (None, None) => None,
         };
+        Modal::new(
+            self.toasts.view(
+                Column::new().push(self.toolbar()).push(self.content()),
+                Message::DismissToast,
+            ),
+            dialog,
+        )
+        .on_close(move || {
+            if error_shown {
+                Message::DismissError
+            } else {
+                Message::CloseFileDialog
+            }
+        })
+        .into()
+    }
+
+    fn theme(&self) -> Self::Theme {
+        match self.theme_mode {
+            ThemeMode::System => self.color_scheme.theme(),
+            ThemeMode::Light => Theme::Light,
+            ThemeMode::Dark => Theme::Dark,
+            ThemeMode::DarkMute => Theme::DarkMute,
+            ThemeMode::Custom => self
+                .custom_theme
+                .clone()
+                .unwrap_or_else(|| self.color_scheme.theme()),
+        }
+    }
+}
 
+impl App {
+    /// View the toolbar shown above all content.
+    fn toolbar(&self) -> Element<'_, Message, Renderer> {
+        let tab_sidebar = self.tab_sidebar;
+        Row::new()
+            .push(
+                TextButton::new_with_on_press(&"new…", || {
+                    Message::OpenFileDialog(FilePurpose::New)
+                })
+                .icon(Icon::Add)
+                .tooltip(&"create an empty bookmark file"),
+            )
+            .push(
+                TextButton::new_with_on_press(&"open…", || {
+                    Message::OpenFileDialog(FilePurpose::Open)
+                })
+                .icon(Icon::Open)
+                .tooltip(&"open a bookmark file"),
+            )
+            .push(
+                pick_list(
+                    self.settings
+                        .recent_files
+                        .iter()
+                        .cloned()
+                        .map(RecentFile)
+                        .collect::<Vec<_>>(),
+                    None,
+                    |RecentFile(path)| Message::LoadFile(path),
+                )
+                .placeholder("open recent"),
+            )
+            .push(text("theme"))
+            .push(pick_list(
+                ThemeMode::BUILTIN
+                    .into_iter()
+                    .chain(self.custom_theme.as_ref().map(|_| ThemeMode::Custom))
+                    .collect::<Vec<_>>(),
+                Some(self.theme_mode),
+                Message::SetThemeMode,
+            ))
+            .push(
+                TextButton::new_with_on_press(
+                    if tab_sidebar {
+                        &"side tabs"
+                    } else {
+                        &"top tabs"
+                    },
+                    move || Message::SetTabSidebar(!tab_sidebar),
+                )
+                .style(text_button::Style::Secondary)
+                .tooltip(if tab_sidebar {
+                    &"show tabs above the content"
+                } else {
+                    &"show tabs beside the content"
+                }),
+            )
+            .push(pick_list(Vec::from(TabKind::ALL), None, Message::AddTab).placeholder("open tab"))
+            .spacing(3)
+            .padding(3)
+            .align_items(Alignment::Center)
+            .into()
+    }
+
+    /// View the loaded data.
+    fn content(&self) -> Element<'_, Message, Renderer> {
         Tabs::new(
             &self.tabs,
             self.selected_tab,
             Message::SelTab,
-            |tab_state| match tab_state.as_str() {
-                "bookmarks" => file_data
-                    .bookmark
+            |tab| match (tab, &self.data) {
+                (Tab::Log { filter, offset, id }, _) => self.log_view(filter, *offset, id),
+                (_, None) => Column::new()
+                    .push(text("no data loaded"))
+                    .push(
+                        Row::new()
+                            .push(TextButton::new_with_on_press(&"new collection", || {
+                                Message::OpenFileDialog(FilePurpose::New)
+                            }))
+                            .push(
+                                TextButton::new_with_on_press(&"open file", || {
+                                    Message::OpenFileDialog(FilePurpose::Open)
+                                })
+                                .style(text_button::Style::Secondary),
+                            )
+                            .spacing(6),
+                    )
+                    .spacing(6)
+                    .align_items(Alignment::Center)
+                    .pipe(container)
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .center_x()
+                    .center_y()
+                    .into(),
+                (
+                    Tab::Bookmarks {
+                        search,
+                        shown,
+                        offset,
+                        id,
+                    },
+                    Some(file_data),
+                ) => self.bookmarks(file_data, search, shown, *offset, id),
+                (Tab::Categories, Some(_)) => self
+                    .categories
                     .iter()
-                    .take(128)
-                    .collect_column(|bookmark| {
-                        text(bookmark.url.clone())
-                            .pipe(button)
-                            .on_press(bookmark.uuid)
-                            .style(theme::Button::Text)
-                            .pipe(Element::from)
-                            .map(Message::OpenBookmark)
+                    .enumerate()
+                    .fold(Column::new(), |column, (index, category)| {
+                        self.category_tree(column, category, &[index])
                     })
                     .width(Length::Fill)
                     .pipe(scrollable)
                     .pipe(Element::from),
-                _ => text("no content").into(),
+                (
+                    Tab::Uncategorized {
+                        category,
+                        offset,
+                        id,
+                    },
+                    Some(file_data),
+                ) => self.uncategorized(file_data, category.as_ref(), *offset, id),
             },
         )
+        .horizontal(self.tab_sidebar)
+        .available_width(self.viewport_width)
+        .on_close(Message::CloseTab)
+        .on_reorder(Message::MoveTab)
+        .on_new(|| Message::AddTab(TabKind::Bookmarks))
+        .placeholder(
+            text("no open tabs, press + to open one")
+                .pipe(container)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .center_x()
+                .center_y(),
+        )
         .into()
     }
+
+    /// View the bookmarks of file data not in any category, which can be added to the category
+    /// chosen for the tab.
+    fn uncategorized<'a>(
+        &'a self,
+        file_data: &'a FileData,
+        category: Option<&CategoryChoice>,
+        offset: f32,
+        id: &scrollable::Id,
+    ) -> Element<'a, Message, Renderer> {
+        Column::new()
+            .push(
+                Row::new()
+                    .push(text("sort into"))
+                    .push(pick_list(
+                        CategoryChoice::all(file_data),
+                        category.cloned(),
+                        Message::TriageCategory,
+                    ))
+                    .spacing(3)
+                    .align_items(Alignment::Center),
+            )
+            .push(
+                VirtualList::new(
+                    &self.uncategorized,
+                    offset,
+                    move |&index| {
+                        let bookmark = &file_data.bookmark[index];
+                        Row::new()
+                            .push(
+                                text("add")
+                                    .pipe(button)
+                                    .padding(3)
+                                    .with(category, |btn, _| btn.on_press(bookmark.uuid))
+                                    .pipe(Element::from)
+                                    .map(Message::Categorize),
+                            )
+                            .push(self.delete_button(Deletion::Bookmark(bookmark.uuid)))
+                            .push(text(bookmark.url.clone()))
+                            .spacing(3)
+                            .align_items(Alignment::Center)
+                            .into()
+                    },
+                    Message::TriageScroll,
+                )
+                .row_height(BOOKMARK_ROW_HEIGHT)
+                .viewport_height(self.viewport_height)
+                .id(id.clone()),
+            )
+            .spacing(3)
+            .into()
+    }
+
+    /// Get the settings, updated with the kinds of the open tabs.
+    fn session(&mut self) -> Settings {
+        self.settings.tabs = self.tabs.iter().map(Tab::kind).collect();
+        self.settings.clone()
+    }
+
+    /// Forget the categories chosen in tabs, as their paths may no longer be valid.
+    fn clear_category_choices(&mut self) {
+        for tab in &mut self.tabs {
+            if let Tab::Uncategorized { category, .. } = tab {
+                *category = None;
+            }
+        }
+    }
+
+    /// Open or create the file at a path.
+    fn use_file(&mut self, purpose: FilePurpose, path: PathBuf) -> Command<Message> {
+        match purpose {
+            FilePurpose::Open => self.update(Message::LoadFile(path)),
+            FilePurpose::New => create_file(path, false),
+        }
+    }
+
+    /// Use file data loaded from or created at a path, reporting it with a toast.
+    fn use_data(&mut self, path: PathBuf, file_data: FileData, toast: Toast) -> Command<Message> {
+        self.data = Some(file_data);
+        self.categorize();
+        self.clear_category_choices();
+        self.filter_tabs();
+        self.data_path = Some(path.clone());
+        self.settings.add_recent_file(path);
+        Command::batch([
+            self.toasts.push(toast, Message::DismissToast),
+            self.save_session(),
+        ])
+    }
+
+    /// Scroll the selected tab to the offset kept for it.
+    fn snap_selected_tab(&self) -> Command<Message> {
+        self.tabs
+            .get(self.selected_tab)
+            .map_or_else(Command::none, Tab::snap)
+    }
+
+    /// Update which bookmarks tabs show after the file data or the sorting changed.
+    fn filter_tabs(&mut self) {
+        for tab in &mut self.tabs {
+            tab.filter(self.data.as_ref(), self.bookmark_sort);
+        }
+    }
+
+    /// Evaluate the categories of the loaded file data, after it changed.
+    fn categorize(&mut self) {
+        (self.categories, self.uncategorized) =
+            self.data
+                .as_ref()
+                .map_or_else(Default::default, |file_data| {
+                    let matches = file_data.categorize();
+                    (
+                        matches.iter().map(CategoryCount::from).collect(),
+                        file_data.unmatched(&matches),
+                    )
+                });
+    }
+
+    /// Check if a bookmark is not in any category, as last evaluated by [`App::categorize`].
+    fn is_uncategorized(&self, uuid: uuid::Uuid) -> bool {
+        self.data.as_ref().is_some_and(|file_data| {
+            self.uncategorized
+                .iter()
+                .any(|&index| file_data.bookmark[index].uuid == uuid)
+        })
+    }
+
+    /// Save the loaded file data to the path it was loaded from.
+    fn save_data(&self) -> Command<Message> {
+        match (&self.data, &self.data_path) {
+            (Some(file_data), Some(path)) => {
+                Command::perform(file_data.save(path.clone()), Message::DataSaved)
+            }
+            _ => Command::none(),
+        }
+    }
+
+    /// Save the settings of the session when it changed.
+    fn save_session(&mut self) -> Command<Message> {
+        self.save_settings(Message::SessionSaved)
+            .unwrap_or_else(Command::none)
+    }
+
+    /// Save the settings updated with the session, reporting the result with on saved. Nothing is
+    /// saved if the settings file failed to load, so it is not replaced before it is fixed.
+    fn save_settings(
+        &mut self,
+        on_saved: fn(settings::Result) -> Message,
+    ) -> Option<Command<Message>> {
+        (!self.settings_load_failed).then(|| Command::perform(self.session().save(), on_saved))
+    }
+
+    /// View the shown bookmarks of file data as a list or table with a search input, scrolled to
+    /// offset.
+    fn bookmarks<'a>(
+        &'a self,
+        file_data: &'a FileData,
+        search: &str,
+        shown: &[usize],
+        offset: f32,
+        id: &scrollable::Id,
+    ) -> Element<'a, Message, Renderer> {
+        let shown = shown
+            .iter()
+            .map(|&index| &file_data.bookmark[index])
+            .collect::<Vec<_>>();
+
+        let content = match self.bookmark_view {
+            BookmarkView::List => VirtualList::new(
+                &shown,
+                offset,
+                |bookmark| {
+                    Row::new()
+                        .push(
+                            BookmarkRow::new(&bookmark.info, &bookmark.url, &bookmark.tag)
+                                .on_press(bookmark.uuid)
+                                .width(Length::Fill)
+                                .pipe(Element::from)
+                                .map(Message::OpenBookmark),
+                        )
+                        .push(self.delete_button(Deletion::Bookmark(bookmark.uuid)))
+                        .spacing(3)
+                        .align_items(Alignment::Center)
+                        .into()
+                },
+                Message::BookmarkScroll,
+            )
+            .row_height(BOOKMARK_LIST_ROW_HEIGHT)
+            .viewport_height(self.viewport_height)
+            .id(id.clone())
+            .pipe(Element::from),
+            BookmarkView::Table => self.bookmark_table(&shown, offset, id),
+        };
+
+        Column::new()
+            .push(
+                Row::new()
+                    .push(
+                        text_input("search", search, |search| search)
+                            .padding(3)
+                            .pipe(Element::from)
+                            .map(Message::SearchBookmarks),
+                    )
+                    .push(
+                        [BookmarkView::List, BookmarkView::Table]
+                            .into_iter()
+                            .collect_row(|view| {
+                                text(view.to_string())
+                                    .pipe(button)
+                                    .padding(3)
+                                    .with(
+                                        (view != self.bookmark_view).then_some(view),
+                                        button::Button::on_press,
+                                    )
+                                    .pipe(Element::from)
+                                    .map(Message::SetBookmarkView)
+                            })
+                            .spacing(3),
+                    )
+                    .spacing(3)
+                    .align_items(Alignment::Center),
+            )
+            .push(content)
+            .spacing(3)
+            .into()
+    }
+
+    /// View bookmarks as a table with sortable, resizable columns, scrolled to offset.
+    fn bookmark_table<'a>(
+        &'a self,
+        shown: &[&'a BookmarkData],
+        offset: f32,
+        id: &scrollable::Id,
+    ) -> Element<'a, Message, Renderer> {
+        Table::new(shown, offset, Message::BookmarkScroll)
+            .push(
+                TableColumn::new("info", |bookmark: &&BookmarkData| {
+                    text(bookmark.info.clone()).into()
+                })
+                .width(self.column_width[0])
+                .sortable(),
+            )
+            .push(
+                TableColumn::new("url", |bookmark: &&BookmarkData| {
+                    text(bookmark.url.clone())
+                        .pipe(button)
+                        .on_press(bookmark.uuid)
+                        .style(theme::Button::Theme(Var::Alt))
+                        .pipe(Element::from)
+                        .map(Message::OpenBookmark)
+                })
+                .width(self.column_width[1])
+                .sortable(),
+            )
+            .push(
+                TableColumn::new("tag", |bookmark: &&BookmarkData| {
+                    text(bookmark.tag.join(", ")).into()
+                })
+                .width(self.column_width[2])
+                .sortable(),
+            )
+            .push(
+                TableColumn::new("created", |bookmark: &&BookmarkData| {
+                    text(format_time(bookmark.created)).into()
+                })
+                .width(self.column_width[3])
+                .sortable(),
+            )
+            .push(
+                TableColumn::new("modified", |bookmark: &&BookmarkData| {
+                    text(format_time(bookmark.modified)).into()
+                })
+                .width(self.column_width[4])
+                .sortable(),
+            )
+            .push(
+                TableColumn::new("", |bookmark: &&BookmarkData| {
+                    self.delete_button(Deletion::Bookmark(bookmark.uuid))
+                })
+                .width(self.column_width[5]),
+            )
+            .sort(self.bookmark_sort)
+            .on_sort(Message::SortBookmarks)
+            .on_resize(Message::ResizeColumn)
+            .row_height(BOOKMARK_ROW_HEIGHT)
+            .viewport_height(self.viewport_height)
+            .id(id.clone())
+            .into()
+    }
+
+    /// View the buffered log entries passing a filter, with controls for the filter.
+    fn log_view(
+        &self,
+        filter: &LogFilter,
+        offset: f32,
+        id: &scrollable::Id,
+    ) -> Element<'_, Message, Renderer> {
+        let entries = self.log.entries(filter);
+        Column::new()
+            .push(
+                Row::new()
+                    .push(pick_list(
+                        Vec::from(LogFilter::LEVELS),
+                        Some(filter.level),
+                        Message::SetLogLevel,
+                    ))
+                    .push(
+                        text_input("search", &filter.search, |search| search)
+                            .padding(3)
+                            .pipe(Element::from)
+                            .map(Message::SearchLog),
+                    )
+                    .spacing(3)
+                    .align_items(Alignment::Center),
+            )
+            .push(
+                VirtualList::new(
+                    &entries,
+                    offset,
+                    |entry| {
+                        Row::new()
+                            .push(text(format_time(Some(entry.time))).width(Length::Units(180)))
+                            .push(text(entry.level).width(Length::Units(50)))
+                            .push(text(&entry.target).width(Length::Units(150)))
+                            .push(text(&entry.message).width(Length::Fill))
+                            .spacing(6)
+                            .into()
+                    },
+                    Message::LogScroll,
+                )
+                .row_height(LOG_ROW_HEIGHT)
+                .viewport_height(self.viewport_height)
+                .id(id.clone()),
+            )
+            .spacing(3)
+            .into()
+    }
+
+    /// View a dialog reporting an error, and how many more are queued after it.
+    fn error_dialog(&self, error: &str) -> Element<'_, Message, Renderer> {
+        Column::new()
+            .push(text("error").size(24))
+            .push(text(error))
+            .with(
+                (self.errors.len() > 1).then(|| self.errors.len() - 1),
+                |column, more| column.push(text(format!("{more} more after this"))),
+            )
+            .push(
+                TextButton::new_with_on_press(&"dismiss", || Message::DismissError)
+                    .style(text_button::Style::Secondary),
+            )
+            .spacing(6)
+            .max_width(400)
+            .into()
+    }
+
+    /// View a button deleting target, which needs a second press within a timeout.
+    fn delete_button(&self, target: Deletion) -> Element<'_, Message, Renderer> {
+        let armed = matches!(&self.armed, Some((armed, _)) if *armed == target);
+        let arm_target = target.clone();
+        TextButton::new_with_on_press(&"delete", move || Message::Delete(target.clone()))
+            .style(if armed {
+                text_button::Style::Destructive
+            } else {
+                text_button::Style::Secondary
+            })
+            .confirm(armed, move || Message::ArmDelete(arm_target.clone()))
+            .into()
+    }
+
+    /// Push a category and its subcategories onto a column, indented by the length of its path.
+    fn category_tree<'a>(
+        &'a self,
+        column: Column<'a, Message, Renderer>,
+        category: &CategoryCount,
+        path: &[usize],
+    ) -> Column<'a, Message, Renderer> {
+        const INDENT: u16 = 16;
+
+        let depth = u16::try_from(path.len() - 1).unwrap_or(u16::MAX);
+        let name = match category.inheritance {
+            Inheritance::Filter => category.name.clone(),
+            Inheritance::Independent => format!("{} (independent)", category.name),
+        };
+        let mut column = column.push(
+            Row::new()
+                .push(text(format!("{name} [{}]", category.bookmarks)))
+                .push(self.delete_button(Deletion::Category(path.to_vec())))
+                .spacing(3)
+                .align_items(Alignment::Center)
+                .pipe(container)
+                .padding([0, 0, 0, depth * INDENT]),
+        );
+        for (index, subcategory) in category.subcategory.iter().enumerate() {
+            column = self.category_tree(column, subcategory, &[path, &[index]].concat());
+        }
+        if category.subcategory.is_empty() {
+            column
+        } else {
+            let unsorted = format!("<not in any subcategory> [{}]", category.unsorted);
+            column.push(
+                text(unsorted)
+                    .pipe(container)
+                    .padding([0, 0, 0, (depth + 1) * INDENT]),
+            )
+        }
+    }
+}
+
+/// Create an empty bookmark file at a path, only replacing an existing file if replace is set.
+fn create_file(path: PathBuf, replace: bool) -> Command<Message> {
+    let create_path = path.clone();
+    Command::perform(
+        async move {
+            let file_data = FileData::default();
+            if replace {
+                file_data.save(create_path).await?;
+            } else {
+                file_data.create(&create_path).await?;
+            }
+            Ok(file_data)
+        },
+        move |result| Message::FileCreated(path, result),
+    )
+}
+
+/// Pick a file to open with the native file picker of the platform.
+async fn pick_file() -> Option<PathBuf> {
+    rfd::AsyncFileDialog::new()
+        .set_title("Open bookmarks")
+        .pick_file()
+        .await
+        .map(|file| file.path().to_owned())
+}
+
+/// Pick where to create a file with the native file picker of the platform.
+async fn pick_new_file() -> Option<PathBuf> {
+    rfd::AsyncFileDialog::new()
+        .set_title("New bookmarks")
+        .save_file()
+        .await
+        .map(|file| file.path().to_owned())
+}
+
+/// Format an optional point in time for display.
+fn format_time(time: Option<SystemTime>) -> String {
+    time.map(|time| humantime::format_rfc3339_seconds(time).to_string())
+        .unwrap_or_default()
 }
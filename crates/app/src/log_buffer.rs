@@ -0,0 +1,174 @@
+//! In memory log of tracing events, shown in the log tab.
+
+use std::{
+    collections::VecDeque,
+    fmt::{self, Write},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::SystemTime,
+};
+use tracing::{
+    field::{Field, Visit},
+    Event, Level, Subscriber,
+};
+use tracing_subscriber::{layer::Context, Layer};
+
+/// A logged tracing event.
+#[derive(Clone, Debug)]
+pub struct LogEntry {
+    /// When the event happened.
+    pub time: SystemTime,
+    /// Level of the event.
+    pub level: Level,
+    /// Target of the event, usually the module it happened in.
+    pub target: String,
+    /// Message of the event followed by its other fields.
+    pub message: String,
+}
+
+/// Which [`LogEntry`] values are shown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogFilter {
+    /// Most verbose level shown.
+    pub level: Level,
+    /// Text the target or message of shown entries contain, ignoring case.
+    pub search: String,
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        Self {
+            level: Level::INFO,
+            search: String::new(),
+        }
+    }
+}
+
+impl LogFilter {
+    /// Levels which can be filtered by, least verbose first.
+    pub const LEVELS: [Level; 5] = [
+        Level::ERROR,
+        Level::WARN,
+        Level::INFO,
+        Level::DEBUG,
+        Level::TRACE,
+    ];
+
+    /// Get a function checking if an entry passes the filter, lowercasing the search only once
+    /// for every entry checked.
+    pub fn matcher(&self) -> impl '_ + Fn(&LogEntry) -> bool {
+        let search = self.search.to_lowercase();
+        move |entry| {
+            entry.level <= self.level
+                && (entry.target.to_lowercase().contains(&search)
+                    || entry.message.to_lowercase().contains(&search))
+        }
+    }
+}
+
+/// A shared ring buffer of the latest [`LogEntry`] values, filled by its [`LogLayer`].
+#[derive(Clone, Debug)]
+pub struct LogBuffer {
+    entries: Arc<Mutex<VecDeque<LogEntry>>>,
+    capacity: usize,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::new(Self::CAPACITY)
+    }
+}
+
+impl LogBuffer {
+    /// Default amount of entries kept.
+    pub const CAPACITY: usize = 1000;
+
+    /// Create a new empty [`LogBuffer`] keeping at most capacity entries.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+
+    /// Get a [`LogLayer`] pushing events to this buffer.
+    #[must_use]
+    pub fn layer(&self) -> LogLayer {
+        LogLayer {
+            buffer: self.clone(),
+        }
+    }
+
+    /// Get the buffered entries passing a filter, oldest first.
+    #[must_use]
+    pub fn entries(&self, filter: &LogFilter) -> Vec<LogEntry> {
+        let matches = filter.matcher();
+        self.lock()
+            .iter()
+            .filter(|entry| matches(entry))
+            .cloned()
+            .collect()
+    }
+
+    /// Push an entry, dropping the oldest if the buffer is full.
+    fn push(&self, entry: LogEntry) {
+        let mut entries = self.lock();
+        while entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    /// Lock the entries, a panic while they were locked cannot leave them inconsistent.
+    fn lock(&self) -> MutexGuard<'_, VecDeque<LogEntry>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A tracing subscriber layer pushing events to a [`LogBuffer`].
+#[derive(Debug)]
+pub struct LogLayer {
+    buffer: LogBuffer,
+}
+
+impl<S> Layer<S> for LogLayer
+where
+    S: Subscriber,
+{
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        self.buffer.push(LogEntry {
+            time: SystemTime::now(),
+            level: *metadata.level(),
+            target: metadata.target().to_owned(),
+            message: visitor.message + &visitor.fields,
+        });
+    }
+}
+
+/// Visitor collecting the message and other fields of an event.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={value}", field.name());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{value:?}");
+        } else {
+            let _ = write!(self.fields, " {}={value:?}", field.name());
+        }
+    }
+}
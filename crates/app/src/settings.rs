@@ -0,0 +1,173 @@
+//! Settings persisted between runs.
+
+use crate::workspace::TabKind;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, fs as std_fs, io, path::PathBuf, result};
+use thiserror::Error;
+use tokio::fs;
+
+/// Error type for loading and saving settings.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Forward for IO errors.
+    #[error(transparent)]
+    IO(#[from] io::Error),
+    /// Forward for toml deserialization errors.
+    #[error(transparent)]
+    TomlDe(#[from] toml::de::Error),
+    /// Forward for toml serialization errors.
+    #[error(transparent)]
+    TomlSer(#[from] toml::ser::Error),
+    /// The config directory of the platform could not be found.
+    #[error("could not find config directory")]
+    NoConfigDir,
+}
+
+/// Result type for loading and saving settings.
+pub type Result<T = ()> = result::Result<T, Error>;
+
+/// Which theme is used.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeMode {
+    /// Follow the light/dark preference of the desktop.
+    #[default]
+    System,
+    /// Always use the light theme.
+    Light,
+    /// Always use the dark theme.
+    Dark,
+    /// Always use the muted dark theme.
+    DarkMute,
+    /// Use the theme loaded from a theme file.
+    Custom,
+}
+
+impl ThemeMode {
+    /// All modes not depending on a theme file.
+    pub const BUILTIN: [ThemeMode; 4] = [
+        ThemeMode::System,
+        ThemeMode::Light,
+        ThemeMode::Dark,
+        ThemeMode::DarkMute,
+    ];
+}
+
+impl Display for ThemeMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeMode::System => write!(f, "system"),
+            ThemeMode::Light => write!(f, "light"),
+            ThemeMode::Dark => write!(f, "dark"),
+            ThemeMode::DarkMute => write!(f, "dark mute"),
+            ThemeMode::Custom => write!(f, "custom"),
+        }
+    }
+}
+
+/// Size of the window in logical pixels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowSize {
+    /// Width of the window.
+    pub width: u32,
+    /// Height of the window.
+    pub height: u32,
+}
+
+impl Default for WindowSize {
+    fn default() -> Self {
+        Self {
+            width: 1024,
+            height: 768,
+        }
+    }
+}
+
+/// Application settings, stored as toml in the config directory.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Theme in use.
+    pub theme: ThemeMode,
+    /// Kinds of the open tabs, in order.
+    pub tabs: Vec<TabKind>,
+    /// Recently opened files, most recent first.
+    pub recent_files: Vec<PathBuf>,
+    /// Command bookmarks are opened with, the default browser of the system is used if unset.
+    pub browser: Option<String>,
+    /// Size of the window when it was last closed.
+    pub window: WindowSize,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: ThemeMode::default(),
+            tabs: TabKind::ALL.into(),
+            recent_files: Vec::new(),
+            browser: None,
+            window: WindowSize::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Most recently opened files remembered.
+    pub const MAX_RECENT_FILES: usize = 10;
+
+    /// Move a file to the front of the recently opened files, forgetting the oldest ones past
+    /// [`Settings::MAX_RECENT_FILES`].
+    pub fn add_recent_file(&mut self, path: PathBuf) {
+        self.recent_files.retain(|recent| *recent != path);
+        self.recent_files.insert(0, path);
+        self.recent_files.truncate(Self::MAX_RECENT_FILES);
+    }
+
+    /// Get the path settings are stored at.
+    ///
+    /// # Errors
+    /// If the config directory of the platform cannot be found.
+    pub fn path() -> Result<PathBuf> {
+        Ok(Self::dir()?.join("settings.toml"))
+    }
+
+    /// Get the directory settings are stored in.
+    fn dir() -> Result<PathBuf> {
+        dirs::config_dir()
+            .map(|dir| dir.join("bookmark"))
+            .ok_or(Error::NoConfigDir)
+    }
+
+    /// Load settings, a missing settings file gives the default settings.
+    ///
+    /// # Errors
+    /// If the settings file exists but cannot be read or is wrongly formatted.
+    pub fn load() -> Result<Self> {
+        match std_fs::read_to_string(Self::path()?) {
+            Ok(content) => Ok(toml::from_str(&content)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Save settings, creating the config directory if needed. They are written to a temporary
+    /// file next to the settings file which then replaces it, so overlapping saves never leave a
+    /// partially written file.
+    ///
+    /// # Errors
+    /// If the settings cannot be serialized or written.
+    pub async fn save(self) -> Result {
+        let dir = Self::dir()?;
+        let path = Self::path()?;
+        let content = toml::to_string_pretty(&self)?;
+        fs::create_dir_all(&dir).await?;
+        let temp = tempfile::Builder::new()
+            .prefix(".settings")
+            .tempfile_in(&dir)?
+            .into_temp_path();
+        fs::write(&temp, content).await?;
+        temp.persist(&path).map_err(io::Error::from)?;
+        tracing::info!(path = %path.display(), "saved settings");
+        Ok(())
+    }
+}
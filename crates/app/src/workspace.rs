@@ -0,0 +1,188 @@
+//! Tabs of the workspace and the state kept for each of them.
+
+use crate::{log_buffer::LogFilter, CategoryChoice};
+use bookmark_data::{BookmarkData, FileData};
+use bookmark_ui_util::table::Sort;
+use iced::{
+    widget::scrollable::{self, RelativeOffset},
+    Command,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// What a tab shows, persisted in settings as the open tabs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TabKind {
+    /// All bookmarks, as a list or table.
+    #[default]
+    Bookmarks,
+    /// The category tree with the amount of bookmarks in each category.
+    Categories,
+    /// Bookmarks not in any category, which can be sorted into one.
+    Uncategorized,
+    /// Buffered tracing events.
+    Log,
+}
+
+impl TabKind {
+    /// All kinds, in the order tabs are opened by default.
+    pub const ALL: [TabKind; 4] = [
+        TabKind::Bookmarks,
+        TabKind::Categories,
+        TabKind::Uncategorized,
+        TabKind::Log,
+    ];
+}
+
+impl Display for TabKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TabKind::Bookmarks => write!(f, "bookmarks"),
+            TabKind::Categories => write!(f, "categories"),
+            TabKind::Uncategorized => write!(f, "uncategorized"),
+            TabKind::Log => write!(f, "log"),
+        }
+    }
+}
+
+/// An open tab with the state of its kind.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Tab {
+    /// Tab of kind [`TabKind::Bookmarks`].
+    Bookmarks {
+        /// Text shown bookmarks contain in their info, url or tags, ignoring case.
+        search: String,
+        /// Indices of the bookmarks containing search in the order they are shown, updated by
+        /// [`Tab::filter`].
+        shown: Vec<usize>,
+        /// Relative scroll offset of the list or table.
+        offset: f32,
+        /// Id of the list or table, to restore its offset.
+        id: scrollable::Id,
+    },
+    /// Tab of kind [`TabKind::Categories`].
+    Categories,
+    /// Tab of kind [`TabKind::Uncategorized`].
+    Uncategorized {
+        /// Category bookmarks are sorted into.
+        category: Option<CategoryChoice>,
+        /// Relative scroll offset of the bookmarks.
+        offset: f32,
+        /// Id of the bookmarks, to restore their offset.
+        id: scrollable::Id,
+    },
+    /// Tab of kind [`TabKind::Log`].
+    Log {
+        /// Which entries are shown.
+        filter: LogFilter,
+        /// Relative scroll offset of the entries.
+        offset: f32,
+        /// Id of the entries, to restore their offset.
+        id: scrollable::Id,
+    },
+}
+
+impl Tab {
+    /// Get the kind of the tab.
+    #[must_use]
+    pub fn kind(&self) -> TabKind {
+        match self {
+            Tab::Bookmarks { .. } => TabKind::Bookmarks,
+            Tab::Categories => TabKind::Categories,
+            Tab::Uncategorized { .. } => TabKind::Uncategorized,
+            Tab::Log { .. } => TabKind::Log,
+        }
+    }
+
+    /// Get a command scrolling the content of the tab to the offset kept for it, needed after the
+    /// offset is reset or the tab is shown again.
+    #[must_use]
+    pub fn snap<Message: 'static>(&self) -> Command<Message> {
+        match self {
+            Tab::Bookmarks { offset, id, .. }
+            | Tab::Uncategorized { offset, id, .. }
+            | Tab::Log { offset, id, .. } => {
+                scrollable::snap_to(id.clone(), RelativeOffset { x: 0.0, y: *offset })
+            }
+            Tab::Categories => Command::none(),
+        }
+    }
+
+    /// Update which bookmarks are shown and their order, after the search of the tab, the file
+    /// data or the sorting of the bookmark table changed.
+    pub fn filter(&mut self, file_data: Option<&FileData>, sort: Option<Sort>) {
+        let Tab::Bookmarks { search, shown, .. } = self else {
+            return;
+        };
+        let search = search.to_lowercase();
+        let contains = |value: &String| value.to_lowercase().contains(&search);
+        *shown = file_data.map_or_else(Vec::new, |file_data| {
+            file_data
+                .bookmark
+                .iter()
+                .enumerate()
+                .filter(|(_, bookmark)| {
+                    contains(&bookmark.info)
+                        || contains(&bookmark.url)
+                        || bookmark.tag.iter().any(contains)
+                })
+                .map(|(index, _)| index)
+                .collect()
+        });
+        if let (Some(file_data), Some(sort)) = (file_data, sort) {
+            sort_bookmarks(&file_data.bookmark, shown, sort);
+        }
+    }
+}
+
+/// Sort indices of bookmarks by a column of the bookmark table, which are info, url, tag, created
+/// and modified, followed by columns which cannot be sorted by.
+fn sort_bookmarks(bookmark: &[BookmarkData], order: &mut [usize], sort: Sort) {
+    match sort.column {
+        0 => order.sort_by_cached_key(|&index| bookmark[index].info.to_lowercase()),
+        1 => order.sort_by(|&a, &b| bookmark[a].url.cmp(&bookmark[b].url)),
+        2 => order.sort_by_cached_key(|&index| bookmark[index].tag.join(", ")),
+        3 => order.sort_by_key(|&index| bookmark[index].created),
+        4 => order.sort_by_key(|&index| bookmark[index].modified),
+        _ => return,
+    }
+    if sort.descending {
+        order.reverse();
+    }
+}
+
+impl From<TabKind> for Tab {
+    fn from(value: TabKind) -> Self {
+        match value {
+            TabKind::Bookmarks => Tab::Bookmarks {
+                search: String::new(),
+                shown: Vec::new(),
+                offset: 0.0,
+                id: scrollable::Id::unique(),
+            },
+            TabKind::Categories => Tab::Categories,
+            TabKind::Uncategorized => Tab::Uncategorized {
+                category: None,
+                offset: 0.0,
+                id: scrollable::Id::unique(),
+            },
+            TabKind::Log => Tab::Log {
+                filter: LogFilter::default(),
+                offset: 0.0,
+                id: scrollable::Id::unique(),
+            },
+        }
+    }
+}
+
+impl Display for Tab {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tab::Bookmarks { search, .. } if !search.is_empty() => {
+                write!(f, "bookmarks: {search}")
+            }
+            _ => write!(f, "{}", self.kind()),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Tests for buffering and filtering tracing events.
+
+use bookmark_app::log_buffer::{LogBuffer, LogEntry, LogFilter};
+use std::time::SystemTime;
+use tracing::Level;
+use tracing_subscriber::{layer::SubscriberExt, Registry};
+
+fn entry(level: Level, target: &str, message: &str) -> LogEntry {
+    LogEntry {
+        time: SystemTime::now(),
+        level,
+        target: target.into(),
+        message: message.into(),
+    }
+}
+
+fn all() -> LogFilter {
+    LogFilter {
+        level: Level::TRACE,
+        search: String::new(),
+    }
+}
+
+#[test]
+fn buffer_evicts_oldest() {
+    let buffer = LogBuffer::new(3);
+    let subscriber = Registry::default().with(buffer.layer());
+    tracing::subscriber::with_default(subscriber, || {
+        for index in 0..5 {
+            tracing::info!("event {index}");
+        }
+    });
+    let messages = buffer
+        .entries(&all())
+        .into_iter()
+        .map(|entry| entry.message)
+        .collect::<Vec<_>>();
+    assert_eq!(messages, ["event 2", "event 3", "event 4"]);
+}
+
+#[test]
+fn buffer_records_fields() {
+    let buffer = LogBuffer::new(1);
+    let subscriber = Registry::default().with(buffer.layer());
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::warn!(count = 2, "loaded");
+    });
+    let entries = buffer.entries(&all());
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].level, Level::WARN);
+    assert_eq!(entries[0].message, "loaded count=2");
+}
+
+#[test]
+fn filter_by_level() {
+    let filter = LogFilter {
+        level: Level::WARN,
+        search: String::new(),
+    };
+    let matches = filter.matcher();
+    assert!(matches(&entry(Level::ERROR, "app", "failed")));
+    assert!(matches(&entry(Level::WARN, "app", "low contrast")));
+    assert!(!matches(&entry(Level::INFO, "app", "loaded")));
+}
+
+#[test]
+fn filter_by_search_ignoring_case() {
+    let filter = LogFilter {
+        level: Level::TRACE,
+        search: "Data".into(),
+    };
+    let matches = filter.matcher();
+    assert!(matches(&entry(Level::INFO, "bookmark_data", "loaded")));
+    assert!(matches(&entry(Level::INFO, "app", "saved file DATA")));
+    assert!(!matches(&entry(Level::INFO, "app", "settings saved")));
+}
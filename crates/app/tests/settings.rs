@@ -0,0 +1,41 @@
+//! Tests for settings persisted between runs.
+
+use bookmark_app::settings::Settings;
+use std::path::PathBuf;
+
+#[test]
+fn recent_files_most_recent_first() {
+    let mut settings = Settings::default();
+    settings.add_recent_file(PathBuf::from("a"));
+    settings.add_recent_file(PathBuf::from("b"));
+    assert_eq!(
+        settings.recent_files,
+        [PathBuf::from("b"), PathBuf::from("a")]
+    );
+}
+
+#[test]
+fn recent_files_deduplicated() {
+    let mut settings = Settings::default();
+    for path in ["a", "b", "a"] {
+        settings.add_recent_file(PathBuf::from(path));
+    }
+    assert_eq!(
+        settings.recent_files,
+        [PathBuf::from("a"), PathBuf::from("b")]
+    );
+}
+
+#[test]
+fn recent_files_capped() {
+    let mut settings = Settings::default();
+    for index in 0..=Settings::MAX_RECENT_FILES {
+        settings.add_recent_file(PathBuf::from(index.to_string()));
+    }
+    assert_eq!(settings.recent_files.len(), Settings::MAX_RECENT_FILES);
+    assert_eq!(
+        settings.recent_files.first(),
+        Some(&PathBuf::from(Settings::MAX_RECENT_FILES.to_string()))
+    );
+    assert!(!settings.recent_files.contains(&PathBuf::from("0")));
+}
@@ -0,0 +1,71 @@
+//! Tests for the state kept for tabs.
+
+use bookmark_app::workspace::{Tab, TabKind};
+use bookmark_data::{BookmarkData, FileData};
+use bookmark_ui_util::table::Sort;
+
+fn file_data() -> FileData {
+    let bookmark = |info: &str, url: &str, tag: &[&str]| BookmarkData {
+        info: info.into(),
+        url: url.into(),
+        tag: tag.iter().map(|&tag| tag.to_owned()).collect(),
+        ..BookmarkData::default()
+    };
+    FileData {
+        bookmark: vec![
+            bookmark("Example", "https://example.com", &[]),
+            bookmark("Docs", "https://docs.rs", &["Rust"]),
+            bookmark("Other", "https://other.org", &["misc"]),
+        ],
+        ..FileData::default()
+    }
+}
+
+fn shown(tab: &Tab) -> &[usize] {
+    match tab {
+        Tab::Bookmarks { shown, .. } => shown,
+        _ => panic!("not a bookmarks tab"),
+    }
+}
+
+#[test]
+fn filter_by_search_ignoring_case() {
+    let file_data = file_data();
+    let mut tab = Tab::from(TabKind::Bookmarks);
+    tab.filter(Some(&file_data), None);
+    assert_eq!(shown(&tab), [0, 1, 2]);
+
+    for (search, expected) in [("EXAMPLE", &[0][..]), ("rust", &[1]), ("o", &[0, 1, 2])] {
+        if let Tab::Bookmarks {
+            search: tab_search, ..
+        } = &mut tab
+        {
+            *tab_search = search.into();
+        }
+        tab.filter(Some(&file_data), None);
+        assert_eq!(shown(&tab), expected, "searching {search}");
+    }
+}
+
+#[test]
+fn filter_without_data_shows_nothing() {
+    let mut tab = Tab::from(TabKind::Bookmarks);
+    tab.filter(None, None);
+    assert!(shown(&tab).is_empty());
+}
+
+#[test]
+fn filter_sorts_by_column() {
+    let file_data = file_data();
+    let mut tab = Tab::from(TabKind::Bookmarks);
+    for (column, descending, expected) in [
+        (0, false, [1, 0, 2]),
+        (0, true, [2, 0, 1]),
+        (1, false, [1, 0, 2]),
+        (2, false, [0, 1, 2]),
+        (5, true, [0, 1, 2]),
+    ] {
+        tab.filter(Some(&file_data), Some(Sort { column, descending }));
+        assert_eq!(shown(&tab), expected, "sorting column {column}");
+    }
+}
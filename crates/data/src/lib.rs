@@ -10,10 +10,16 @@
 
 use deepsize::DeepSizeOf;
 use serde::{Deserialize, Serialize};
-use std::{io, mem, path::PathBuf, result};
+use std::{
+    future::Future,
+    io, mem,
+    path::{Path, PathBuf},
+    result,
+    time::SystemTime,
+};
 use tap::Pipe;
 use thiserror::Error;
-use tokio::fs;
+use tokio::{fs, io::AsyncWriteExt};
 use uuid::Uuid;
 
 /// Error type for bookmark data.
@@ -25,6 +31,9 @@ pub enum Error {
     /// Forward for message pack deserialization errors.
     #[error(transparent)]
     RmpDeserialize(#[from] rmp_serde::decode::Error),
+    /// Forward for message pack serialization errors.
+    #[error(transparent)]
+    RmpSerialize(#[from] rmp_serde::encode::Error),
 }
 
 /// Result type for bookmark data.
@@ -52,6 +61,19 @@ pub struct CategoryData {
     pub identifier: IdentifierData,
     /// Any subcategories of category.
     pub subcategory: Vec<CategoryData>,
+    /// How the category relates to the bookmarks of its parent.
+    #[serde(default)]
+    pub inheritance: Inheritance,
+}
+
+/// Determines which bookmarks a subcategory considers.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Serialize, Deserialize, DeepSizeOf)]
+pub enum Inheritance {
+    /// Only bookmarks that are in the parent category are considered.
+    #[default]
+    Filter,
+    /// All bookmarks are considered, the parent category is only used for grouping.
+    Independent,
 }
 
 /// Sorting rules for a category.
@@ -79,6 +101,25 @@ pub struct BookmarkData {
     pub uuid: Uuid,
     /// Any tags which may be used to find the bookmark.
     pub tag: Vec<String>,
+    /// When the bookmark was created, if known.
+    #[serde(default)]
+    pub created: Option<SystemTime>,
+    /// When the bookmark was last modified, if known.
+    #[serde(default)]
+    pub modified: Option<SystemTime>,
+}
+
+/// Result of evaluating a [`CategoryData`] against the bookmarks of a [`FileData`].
+#[derive(Debug, Clone)]
+pub struct CategoryMatch<'a> {
+    /// The category that was evaluated.
+    pub category: &'a CategoryData,
+    /// Indices of the bookmarks in the category.
+    pub bookmark: Vec<usize>,
+    /// Indices of the bookmarks in the category that are not in any subcategory.
+    pub unsorted: Vec<usize>,
+    /// Results for the subcategories of the category.
+    pub subcategory: Vec<CategoryMatch<'a>>,
 }
 
 impl DeepSizeOf for BookmarkData {
@@ -101,7 +142,68 @@ impl FileData {
     /// # Errors
     /// If the file does not exist or if it is wrongly formatted.
     pub async fn load(path: PathBuf) -> Result<Self> {
-        Ok(fs::read(path).await?.pipe_deref(rmp_serde::from_slice)?)
+        tracing::debug!(path = %path.display(), "loading file data");
+        let file_data: Self = fs::read(&path).await?.pipe_deref(rmp_serde::from_slice)?;
+        tracing::info!(
+            path = %path.display(),
+            bookmarks = file_data.bookmark.len(),
+            "loaded file data"
+        );
+        Ok(file_data)
+    }
+
+    /// Create a new bookmark file at a path holding the data.
+    ///
+    /// # Errors
+    /// If a file already exists at the path, if it cannot be written or if the data cannot be
+    /// serialized.
+    pub async fn create(&self, path: &Path) -> Result {
+        let content = rmp_serde::to_vec_named(self)?;
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .await?;
+        file.write_all(&content).await?;
+        file.flush().await?;
+        tracing::info!(
+            path = %path.display(),
+            categories = self.category.len(),
+            "created file data"
+        );
+        Ok(())
+    }
+
+    /// Save the data to a bookmark file at a path, replacing it once the data is written next to
+    /// it. The data is serialized before the returned future is awaited.
+    ///
+    /// # Errors
+    /// If the data cannot be serialized or if the file cannot be written.
+    pub fn save(&self, path: PathBuf) -> impl Future<Output = Result> {
+        let content = rmp_serde::to_vec_named(self);
+        let bookmarks = self.bookmark.len();
+        async move {
+            let content = content?;
+            // A uniquely named file in the same directory, so saves in flight do not collide and
+            // the file can be renamed over the target.
+            let temp = tempfile::Builder::new()
+                .prefix(".bookmarks")
+                .tempfile_in(path.parent().unwrap_or(Path::new(".")))?
+                .into_temp_path();
+            fs::write(&temp, content).await?;
+            temp.persist(&path).map_err(io::Error::from)?;
+            tracing::info!(path = %path.display(), bookmarks, "saved file data");
+            Ok(())
+        }
+    }
+
+    /// Get empty file data with the categories of a template.
+    #[must_use]
+    pub fn from_template(template: FileData) -> Self {
+        Self {
+            category: template.category,
+            ..Self::default()
+        }
     }
 
     /// Get the size of loaded data in bytes.
@@ -109,15 +211,163 @@ impl FileData {
     pub fn storage_size(&self) -> usize {
         self.deep_size_of()
     }
+
+    /// Evaluate all categories against the bookmarks, respecting the [`Inheritance`] of
+    /// subcategories.
+    #[must_use]
+    pub fn categorize(&self) -> Vec<CategoryMatch<'_>> {
+        let all = (0..self.bookmark.len()).collect::<Vec<_>>();
+        let matches = self
+            .category
+            .iter()
+            .map(|category| category.evaluate(&self.bookmark, &all, &all))
+            .collect::<Vec<_>>();
+        tracing::trace!(
+            categories = matches.len(),
+            bookmarks = all.len(),
+            "matched bookmarks against categories"
+        );
+        matches
+    }
+
+    /// Get the bookmarks not in any category or subcategory, as evaluated by
+    /// [`FileData::categorize`].
+    pub fn uncategorized(&self) -> impl Iterator<Item = &BookmarkData> {
+        self.unmatched(&self.categorize())
+            .into_iter()
+            .map(|index| &self.bookmark[index])
+    }
+
+    /// Get the indices of the bookmarks not in any of the matched categories or their
+    /// subcategories, where matches were returned by [`FileData::categorize`].
+    #[must_use]
+    pub fn unmatched(&self, matches: &[CategoryMatch]) -> Vec<usize> {
+        let mut categorized = vec![false; self.bookmark.len()];
+        for category in matches {
+            category.mark(&mut categorized);
+        }
+        categorized
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, categorized)| (!categorized).then_some(index))
+            .collect()
+    }
+
+    /// Get a category by its path of indices, the first index being into `category` and the
+    /// following ones into `subcategory` of the previous.
+    #[must_use]
+    pub fn category_mut(&mut self, path: &[usize]) -> Option<&mut CategoryData> {
+        let (first, rest) = path.split_first()?;
+        rest.iter()
+            .try_fold(self.category.get_mut(*first)?, |category, index| {
+                category.subcategory.get_mut(*index)
+            })
+    }
+
+    /// Remove a category by its path of indices, see [`FileData::category_mut`].
+    pub fn remove_category(&mut self, path: &[usize]) -> Option<CategoryData> {
+        let (last, parent) = path.split_last()?;
+        let siblings = if parent.is_empty() {
+            &mut self.category
+        } else {
+            &mut self.category_mut(parent)?.subcategory
+        };
+        (*last < siblings.len()).then(|| siblings.remove(*last))
+    }
+
+    /// Remove a bookmark by its uuid.
+    pub fn remove_bookmark(&mut self, uuid: Uuid) -> Option<BookmarkData> {
+        let index = self
+            .bookmark
+            .iter()
+            .position(|bookmark| bookmark.uuid == uuid)?;
+        Some(self.bookmark.remove(index))
+    }
+}
+
+impl CategoryData {
+    /// Evaluate the category, where `all` are the indices of every bookmark and `parent` the
+    /// indices of the bookmarks in the parent category.
+    fn evaluate<'a>(
+        &'a self,
+        bookmark: &[BookmarkData],
+        all: &[usize],
+        parent: &[usize],
+    ) -> CategoryMatch<'a> {
+        let candidates = match self.inheritance {
+            Inheritance::Filter => parent,
+            Inheritance::Independent => all,
+        };
+        let matched = candidates
+            .iter()
+            .copied()
+            .filter(|&index| self.identifier.matches(&bookmark[index]))
+            .collect::<Vec<_>>();
+        let subcategory = self
+            .subcategory
+            .iter()
+            .map(|category| category.evaluate(bookmark, all, &matched))
+            .collect::<Vec<_>>();
+        let mut sorted = vec![false; bookmark.len()];
+        subcategory
+            .iter()
+            .flat_map(|category| &category.bookmark)
+            .for_each(|&index| sorted[index] = true);
+        let unsorted = matched
+            .iter()
+            .copied()
+            .filter(|&index| !sorted[index])
+            .collect();
+        CategoryMatch {
+            category: self,
+            bookmark: matched,
+            unsorted,
+            subcategory,
+        }
+    }
+}
+
+impl CategoryMatch<'_> {
+    /// Mark the bookmarks of the category and of all its subcategories.
+    fn mark(&self, marked: &mut [bool]) {
+        self.bookmark.iter().for_each(|&index| marked[index] = true);
+        self.subcategory
+            .iter()
+            .for_each(|category| category.mark(marked));
+    }
+}
+
+impl IdentifierData {
+    /// Check if a bookmark is matched by the identifier.
+    ///
+    /// A bookmark matches if its url is in `whole`, or if it contains every substring in
+    /// `require` and at least one in `include`. When `include` is empty a non-empty `require` is
+    /// enough on its own.
+    #[must_use]
+    pub fn matches(&self, bookmark: &BookmarkData) -> bool {
+        let url = bookmark.url.as_str();
+        if self.whole.iter().any(|whole| whole == url) {
+            return true;
+        }
+        if self.require.is_empty() && self.include.is_empty() {
+            return false;
+        }
+        let contains = |part: &String| url.contains(part.as_str());
+        self.require.iter().all(contains)
+            && (self.include.is_empty() || self.include.iter().any(contains))
+    }
 }
 
 impl Default for BookmarkData {
     fn default() -> Self {
+        let now = SystemTime::now();
         Self {
             url: String::new(),
             info: String::new(),
             uuid: Uuid::new_v4(),
             tag: Vec::new(),
+            created: Some(now),
+            modified: Some(now),
         }
     }
 }
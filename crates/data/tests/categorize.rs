@@ -0,0 +1,169 @@
+//! Tests for matching bookmarks against categories.
+
+use bookmark_data::{BookmarkData, CategoryData, FileData, IdentifierData, Inheritance};
+
+fn bookmark(url: &str) -> BookmarkData {
+    BookmarkData {
+        url: url.into(),
+        ..BookmarkData::default()
+    }
+}
+
+fn identifier(require: &[&str], whole: &[&str], include: &[&str]) -> IdentifierData {
+    let owned = |parts: &[&str]| parts.iter().map(|&part| part.to_owned()).collect();
+    IdentifierData {
+        require: owned(require),
+        whole: owned(whole),
+        include: owned(include),
+    }
+}
+
+fn category(
+    identifier: IdentifierData,
+    inheritance: Inheritance,
+    subcategory: Vec<CategoryData>,
+) -> CategoryData {
+    CategoryData {
+        identifier,
+        inheritance,
+        subcategory,
+        ..CategoryData::default()
+    }
+}
+
+#[test]
+fn empty_identifier_matches_nothing() {
+    let empty = IdentifierData::default();
+    assert!(!empty.matches(&bookmark("https://example.com")));
+    assert!(!empty.matches(&bookmark("")));
+}
+
+#[test]
+fn whole_matches_exactly() {
+    let identifier = identifier(&[], &["https://example.com"], &[]);
+    assert!(identifier.matches(&bookmark("https://example.com")));
+    assert!(!identifier.matches(&bookmark("https://example.com/page")));
+}
+
+#[test]
+fn require_needs_every_part() {
+    let identifier = identifier(&["example", "https"], &[], &[]);
+    assert!(identifier.matches(&bookmark("https://example.com")));
+    assert!(!identifier.matches(&bookmark("http://example.com")));
+}
+
+#[test]
+fn include_needs_any_part() {
+    let identifier = identifier(&[], &[], &["example", "sample"]);
+    assert!(identifier.matches(&bookmark("https://sample.org")));
+    assert!(!identifier.matches(&bookmark("https://other.org")));
+}
+
+#[test]
+fn require_and_include_combine() {
+    let identifier = identifier(&["https"], &[], &["example", "sample"]);
+    assert!(identifier.matches(&bookmark("https://example.com")));
+    assert!(!identifier.matches(&bookmark("http://example.com")));
+    assert!(!identifier.matches(&bookmark("https://other.org")));
+}
+
+#[test]
+fn whole_ignores_require() {
+    let identifier = identifier(&["https"], &["http://example.com"], &[]);
+    assert!(identifier.matches(&bookmark("http://example.com")));
+}
+
+fn file_data(subcategory_inheritance: Inheritance) -> FileData {
+    FileData {
+        category: vec![category(
+            identifier(&[], &[], &["example"]),
+            Inheritance::Filter,
+            vec![category(
+                identifier(&[], &[], &["docs"]),
+                subcategory_inheritance,
+                Vec::new(),
+            )],
+        )],
+        bookmark: vec![
+            bookmark("https://example.com"),
+            bookmark("https://example.com/docs"),
+            bookmark("https://docs.rs"),
+            bookmark("https://other.org"),
+        ],
+        ..FileData::default()
+    }
+}
+
+#[test]
+fn filter_only_considers_parent() {
+    let file_data = file_data(Inheritance::Filter);
+    let matches = file_data.categorize();
+    assert_eq!(matches[0].bookmark, [0, 1]);
+    assert_eq!(matches[0].unsorted, [0]);
+    assert_eq!(matches[0].subcategory[0].bookmark, [1]);
+}
+
+#[test]
+fn independent_considers_all() {
+    let file_data = file_data(Inheritance::Independent);
+    let matches = file_data.categorize();
+    assert_eq!(matches[0].bookmark, [0, 1]);
+    assert_eq!(matches[0].unsorted, [0]);
+    assert_eq!(matches[0].subcategory[0].bookmark, [1, 2]);
+}
+
+#[test]
+fn uncategorized_respects_inheritance() {
+    let urls = |file_data: &FileData| {
+        file_data
+            .uncategorized()
+            .map(|bookmark| bookmark.url.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        urls(&file_data(Inheritance::Filter)),
+        ["https://docs.rs", "https://other.org"]
+    );
+    assert_eq!(
+        urls(&file_data(Inheritance::Independent)),
+        ["https://other.org"]
+    );
+}
+
+#[test]
+fn unmatched_is_uncategorized_indices() {
+    let file_data = file_data(Inheritance::Independent);
+    assert_eq!(file_data.unmatched(&file_data.categorize()), [3]);
+    assert_eq!(file_data.unmatched(&[]), [0, 1, 2, 3]);
+}
+
+#[test]
+fn category_mut_follows_path() {
+    let mut file_data = file_data(Inheritance::Filter);
+    file_data.category[0].name = "example".into();
+    file_data.category[0].subcategory[0].name = "docs".into();
+    let name = |category: Option<&mut CategoryData>| category.map(|category| category.name.clone());
+    assert_eq!(
+        name(file_data.category_mut(&[0])).as_deref(),
+        Some("example")
+    );
+    assert_eq!(
+        name(file_data.category_mut(&[0, 0])).as_deref(),
+        Some("docs")
+    );
+    assert_eq!(name(file_data.category_mut(&[])), None);
+    assert_eq!(name(file_data.category_mut(&[1])), None);
+    assert_eq!(name(file_data.category_mut(&[0, 1])), None);
+}
+
+#[test]
+fn remove_category_by_path() {
+    let mut file_data = file_data(Inheritance::Filter);
+    assert!(file_data.remove_category(&[0, 1]).is_none());
+    assert!(file_data.remove_category(&[]).is_none());
+    assert!(file_data.remove_category(&[0, 0]).is_some());
+    assert!(file_data.category[0].subcategory.is_empty());
+    assert!(file_data.remove_category(&[0]).is_some());
+    assert!(file_data.category.is_empty());
+    assert_eq!(file_data.uncategorized().count(), 4);
+}
@@ -0,0 +1,67 @@
+//! Tests for saving and loading bookmark files.
+
+use bookmark_data::{BookmarkData, FileData};
+use std::{env, path::PathBuf};
+use uuid::Uuid;
+
+fn temp_path() -> PathBuf {
+    env::temp_dir().join(format!("bookmark-data-test-{}", Uuid::new_v4()))
+}
+
+#[tokio::test]
+async fn save_replaces_file() -> anyhow::Result<()> {
+    let path = temp_path();
+    FileData::default().create(&path).await?;
+
+    let mut file_data = FileData::load(path.clone()).await?;
+    file_data.bookmark.push(BookmarkData {
+        url: "https://example.com".into(),
+        ..BookmarkData::default()
+    });
+    file_data.save(path.clone()).await?;
+
+    let loaded = FileData::load(path.clone()).await?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(loaded.bookmark.len(), 1);
+    assert_eq!(loaded.bookmark[0].url, "https://example.com");
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_keeps_existing_file() -> anyhow::Result<()> {
+    let path = temp_path();
+    FileData::default().create(&path).await?;
+    let result = FileData::default().create(&path).await;
+    std::fs::remove_file(&path)?;
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_creates_missing_file() -> anyhow::Result<()> {
+    let path = temp_path();
+    FileData::default().save(path.clone()).await?;
+    let loaded = FileData::load(path.clone()).await?;
+    std::fs::remove_file(&path)?;
+    assert!(loaded.bookmark.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn overlapping_saves_keep_siblings() -> anyhow::Result<()> {
+    let path = temp_path();
+    let sibling = path.with_extension("tmp");
+    std::fs::write(&sibling, "sibling")?;
+
+    let file_data = FileData::default();
+    let saved = tokio::try_join!(file_data.save(path.clone()), file_data.save(path.clone()));
+    let loaded = FileData::load(path.clone()).await;
+    let sibling_content = std::fs::read_to_string(&sibling)?;
+    std::fs::remove_file(&path)?;
+    std::fs::remove_file(&sibling)?;
+
+    saved?;
+    assert!(loaded?.bookmark.is_empty());
+    assert_eq!(sibling_content, "sibling");
+    Ok(())
+}
@@ -1,23 +1,127 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, process};
 
-use bookmark_app::{App, Flags};
-use clap::Parser;
-use iced::{Application, Settings};
+use bookmark_app::{log_buffer::LogBuffer, settings, App, Flags};
+use bookmark_data::FileData;
+use clap::{Parser, Subcommand};
+use iced::{window, Application, Settings};
+use tracing::{level_filters::LevelFilter, Level};
+use tracing_subscriber::{filter::Targets, fmt, prelude::*};
 
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Theme file to use, reloaded when changed.
+    #[arg(long)]
+    theme: Option<PathBuf>,
     files: Vec<PathBuf>,
 }
 
-impl From<Cli> for Flags {
-    fn from(value: Cli) -> Self {
-        Self { files: value.files }
+#[derive(Subcommand)]
+enum Command {
+    /// Print bookmarks not matched by any category.
+    Uncategorized {
+        /// File to read bookmarks from.
+        file: PathBuf,
+    },
+    /// Create a new empty bookmark file.
+    Init {
+        /// Path of the file to create, which must not exist yet.
+        file: PathBuf,
+        /// Bookmark file to copy the categories of, without its bookmarks.
+        #[arg(long)]
+        template: Option<PathBuf>,
+    },
+}
+
+impl Command {
+    fn run(self) -> bookmark_data::Result {
+        let runtime = tokio::runtime::Runtime::new()?;
+        match self {
+            Command::Uncategorized { file } => {
+                let data = runtime.block_on(FileData::load(file))?;
+                for bookmark in data.uncategorized() {
+                    println!("{}\t{}", bookmark.url, bookmark.info);
+                }
+            }
+            Command::Init { file, template } => {
+                let data = match template {
+                    Some(template) => {
+                        FileData::from_template(runtime.block_on(FileData::load(template))?)
+                    }
+                    None => FileData::default(),
+                };
+                runtime.block_on(data.create(&file))?;
+                println!("created {}", file.display());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Cli {
+    /// Get flags for the app, reopening the most recent file if no files are given.
+    fn into_flags(
+        self,
+        settings: settings::Settings,
+        settings_error: Option<String>,
+        log: LogBuffer,
+    ) -> Flags {
+        Flags {
+            files: if self.files.is_empty() {
+                settings.recent_files.iter().take(1).cloned().collect()
+            } else {
+                self.files
+            },
+            theme: self.theme,
+            settings,
+            settings_error,
+            log,
+        }
     }
 }
 
 fn main() -> iced::Result {
+    let mut cli = Cli::parse();
+
+    if let Some(command) = cli.command.take() {
+        if let Err(err) = command.run() {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Events of this workspace are buffered for the log tab, which filters them by level, and
+    // only info and above are printed.
+    let log = LogBuffer::default();
+    tracing_subscriber::registry()
+        .with(fmt::layer().with_filter(LevelFilter::INFO))
+        .with(log.layer())
+        .with(
+            Targets::new()
+                .with_target("bookmark", Level::TRACE)
+                .with_default(Level::WARN),
+        )
+        .init();
+
+    let (settings, settings_error) = match settings::Settings::load() {
+        Ok(settings) => (settings, None),
+        Err(err) => {
+            tracing::warn!("failed to load settings: {err}");
+            (settings::Settings::default(), Some(err.to_string()))
+        }
+    };
+
     App::run(Settings {
-        flags: Cli::parse().into(),
+        window: window::Settings {
+            size: (settings.window.width, settings.window.height),
+            ..Default::default()
+        },
+        // Settings are saved before the app closes the window itself.
+        exit_on_close_request: false,
+        flags: cli.into_flags(settings, settings_error, log),
         ..Default::default()
     })
 }
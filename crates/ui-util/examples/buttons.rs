@@ -95,7 +95,7 @@ impl Application for App {
     }
 
     fn theme(&self) -> Self::Theme {
-        self.theme
+        self.theme.clone()
     }
 
     fn new(_flags: Self::Flags) -> (Self, iced::Command<Self::Message>) {
@@ -0,0 +1,120 @@
+//! Module for [`BookmarkRow`] widget builder.
+
+use crate::{theme, theme::Var, IteratorWidgetExt, Renderer};
+use bookmark_util::AnyWithExt;
+use iced::{
+    widget::{button, container, text, Button, Column},
+    Alignment, Element, Length, Padding,
+};
+use std::marker::PhantomData;
+use tap::Pipe;
+
+/// A widget showing a bookmark by its info, with the url and tags as secondary information.
+#[derive(Debug)]
+pub struct BookmarkRow<'a, Message> {
+    _lifetime: PhantomData<&'a Message>,
+    info: String,
+    url: String,
+    tags: Vec<String>,
+    on_press: Option<Message>,
+    width: Option<Length>,
+    padding: Option<Padding>,
+}
+
+impl<Message> BookmarkRow<'_, Message> {
+    /// Construct a new [`BookmarkRow`] from the info, url and tags of a bookmark. If info is
+    /// empty the url is used as the primary label.
+    pub fn new(info: &impl ToString, url: &impl ToString, tags: &[impl ToString]) -> Self {
+        Self {
+            _lifetime: PhantomData,
+            info: info.to_string(),
+            url: url.to_string(),
+            tags: tags.iter().map(ToString::to_string).collect(),
+            on_press: None,
+            width: None,
+            padding: None,
+        }
+    }
+
+    /// Sets the message produced when the row is pressed.
+    #[must_use]
+    pub fn on_press(self, on_press: Message) -> Self {
+        Self {
+            on_press: Some(on_press),
+            ..self
+        }
+    }
+
+    /// Sets the width of the [`BookmarkRow`].
+    #[must_use]
+    pub fn width(self, width: Length) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+
+    /// Sets the padding of the [`BookmarkRow`].
+    #[must_use]
+    pub fn padding(self, padding: impl Into<Padding>) -> Self {
+        Self {
+            padding: Some(padding.into()),
+            ..self
+        }
+    }
+}
+
+impl<'a, Message> From<BookmarkRow<'a, Message>> for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+{
+    fn from(value: BookmarkRow<'a, Message>) -> Self {
+        let BookmarkRow {
+            info,
+            url,
+            tags,
+            on_press,
+            width,
+            padding,
+            ..
+        } = value;
+
+        let (primary, secondary) = if info.is_empty() {
+            (url, None)
+        } else {
+            (info, Some(url))
+        };
+
+        Column::new()
+            .push(text(primary))
+            .with(secondary, |column, secondary| {
+                column.push(text(secondary).size(14).style(theme::Text::Dim))
+            })
+            .pipe(|column| {
+                if tags.is_empty() {
+                    column
+                } else {
+                    column.push(
+                        tags.into_iter()
+                            .collect_row(|tag| {
+                                text(tag)
+                                    .size(12)
+                                    .pipe(container)
+                                    .padding([1, 4])
+                                    .style(theme::Container::Theme(Var::Alt))
+                            })
+                            .spacing(3)
+                            .align_items(Alignment::Center),
+                    )
+                }
+            })
+            .spacing(2)
+            .width(Length::Fill)
+            .pipe(button)
+            .style(theme::Button::Theme(Var::Alt))
+            .with(width, Button::width)
+            .padding(padding.unwrap_or(Padding::from(3)))
+            .with(on_press, Button::on_press)
+            .into()
+    }
+}
@@ -1,6 +1,8 @@
 //! Palettes and color manipulation.
 
 use iced::Color;
+use std::fmt::Display;
+use thiserror::Error;
 
 /// A more advanced palette with general rules for current theme.
 #[derive(Clone, Copy, Debug)]
@@ -13,6 +15,170 @@ pub struct ThemePalette {
     pub alt: Palette,
     /// Colors for hovered/highlighted items for alt.
     pub alt_highlight: Palette,
+    /// Colors for items with positive actions or outcomes.
+    pub success: Palette,
+    /// Colors for hovered/highlighted items for success.
+    pub success_highlight: Palette,
+    /// Colors for items with destructive actions or failures.
+    pub danger: Palette,
+    /// Colors for hovered/highlighted items for danger.
+    pub danger_highlight: Palette,
+}
+
+impl ThemePalette {
+    /// Derive a [`ThemePalette`] from a base, alt, success and danger [`ContrastPalette`],
+    /// converted to palettes by convert. The non highlight palettes have their dim color muted
+    /// by mute.
+    #[must_use]
+    pub fn derive(
+        palette: ContrastPalette,
+        palette_alt: ContrastPalette,
+        palette_success: ContrastPalette,
+        palette_danger: ContrastPalette,
+        mute: Option<f32>,
+        convert: impl Fn(ContrastPalette) -> Palette,
+    ) -> Self {
+        Self {
+            mute: convert(palette.mute_dim(mute)),
+            alt: convert(palette_alt.mute_dim(mute)),
+            success: convert(palette_success.mute_dim(mute)),
+            danger: convert(palette_danger.mute_dim(mute)),
+            mute_highlight: convert(palette),
+            alt_highlight: convert(palette_alt),
+            success_highlight: convert(palette_success),
+            danger_highlight: convert(palette_danger),
+        }
+    }
+
+    /// Check every [Palette] against a [`ContrastLevel`].
+    ///
+    /// # Errors
+    /// All color pairs with too little contrast, if there are any.
+    pub fn validate(&self, level: ContrastLevel) -> Result<(), Vec<ContrastIssue>> {
+        let issues = [
+            (PaletteRole::Mute, self.mute),
+            (PaletteRole::MuteHighlight, self.mute_highlight),
+            (PaletteRole::Alt, self.alt),
+            (PaletteRole::AltHighlight, self.alt_highlight),
+            (PaletteRole::Success, self.success),
+            (PaletteRole::SuccessHighlight, self.success_highlight),
+            (PaletteRole::Danger, self.danger),
+            (PaletteRole::DangerHighlight, self.danger_highlight),
+        ]
+        .into_iter()
+        .flat_map(|(role, palette)| {
+            palette
+                .contrast_issues(level)
+                .into_iter()
+                .map(move |(pair, ratio, required)| ContrastIssue {
+                    role,
+                    pair,
+                    ratio,
+                    required,
+                })
+        })
+        .collect::<Vec<_>>();
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+}
+
+/// WCAG conformance level contrast is checked against.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ContrastLevel {
+    /// Minimum contrast, 4.5:1 for text.
+    #[default]
+    AA,
+    /// Enhanced contrast, 7:1 for text.
+    AAA,
+}
+
+impl ContrastLevel {
+    /// Contrast ratio other foreground elements need against their background, the same for
+    /// both levels.
+    pub const NON_TEXT_RATIO: f32 = 3.0;
+
+    /// Get the contrast ratio text needs against its background.
+    #[must_use]
+    pub fn text_ratio(self) -> f32 {
+        match self {
+            ContrastLevel::AA => 4.5,
+            ContrastLevel::AAA => 7.0,
+        }
+    }
+}
+
+/// A [Palette] of a [`ThemePalette`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaletteRole {
+    /// [`ThemePalette::mute`].
+    Mute,
+    /// [`ThemePalette::mute_highlight`].
+    MuteHighlight,
+    /// [`ThemePalette::alt`].
+    Alt,
+    /// [`ThemePalette::alt_highlight`].
+    AltHighlight,
+    /// [`ThemePalette::success`].
+    Success,
+    /// [`ThemePalette::success_highlight`].
+    SuccessHighlight,
+    /// [`ThemePalette::danger`].
+    Danger,
+    /// [`ThemePalette::danger_highlight`].
+    DangerHighlight,
+}
+
+impl Display for PaletteRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PaletteRole::Mute => write!(f, "mute"),
+            PaletteRole::MuteHighlight => write!(f, "mute highlight"),
+            PaletteRole::Alt => write!(f, "alt"),
+            PaletteRole::AltHighlight => write!(f, "alt highlight"),
+            PaletteRole::Success => write!(f, "success"),
+            PaletteRole::SuccessHighlight => write!(f, "success highlight"),
+            PaletteRole::Danger => write!(f, "danger"),
+            PaletteRole::DangerHighlight => write!(f, "danger highlight"),
+        }
+    }
+}
+
+/// A color of a [Palette] compared against its background.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorPair {
+    /// [`Palette::text`] against [`Palette::background`].
+    Text,
+    /// [`Palette::foreground`] against [`Palette::background`].
+    Foreground,
+}
+
+impl Display for ColorPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorPair::Text => write!(f, "text"),
+            ColorPair::Foreground => write!(f, "foreground"),
+        }
+    }
+}
+
+/// A pair of colors in a [`ThemePalette`] with too little contrast.
+#[derive(Error, Clone, Copy, Debug, PartialEq)]
+#[error("{role} {pair} has a contrast ratio of {ratio:.2}:1, needs {required}:1")]
+pub struct ContrastIssue {
+    /// Palette the colors are in.
+    pub role: PaletteRole,
+    /// Colors that were compared.
+    pub pair: ColorPair,
+    /// Contrast ratio of the colors.
+    pub ratio: f32,
+    /// Contrast ratio needed.
+    pub required: f32,
 }
 
 /// The palette for an item in a [`ThemePalette`].
@@ -45,10 +211,30 @@ impl Palette {
             text: text.mute(t),
         }
     }
+
+    /// Get the color pairs of the palette with too little contrast for a [`ContrastLevel`], as
+    /// the pair, its contrast ratio and the ratio needed.
+    #[must_use]
+    pub fn contrast_issues(&self, level: ContrastLevel) -> Vec<(ColorPair, f32, f32)> {
+        [
+            (ColorPair::Text, self.text, level.text_ratio()),
+            (
+                ColorPair::Foreground,
+                self.foreground,
+                ContrastLevel::NON_TEXT_RATIO,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(pair, color, required)| {
+            let ratio = color.contrast_ratio(self.background);
+            (ratio < required).then_some((pair, ratio, required))
+        })
+        .collect()
+    }
 }
 
 /// A simple box palette constisting of colors for background text and border.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ContrastPalette {
     /// Bright color of this palette, in light mode is background.
     pub bright: Color,
@@ -76,6 +262,24 @@ impl ContrastPalette {
         }
     }
 
+    /// Get the default green palette used for success.
+    #[must_use]
+    pub fn success() -> Self {
+        Self {
+            bright: Color::from_rgb8(170, 235, 170),
+            dim: Color::from_rgb8(0, 40, 0),
+        }
+    }
+
+    /// Get the default red palette used for danger.
+    #[must_use]
+    pub fn danger() -> Self {
+        Self {
+            bright: Color::from_rgb8(255, 190, 190),
+            dim: Color::from_rgb8(50, 0, 0),
+        }
+    }
+
     /// Mute the dim color.
     #[must_use]
     pub fn mute_dim(self, t: Option<f32>) -> Self {
@@ -85,6 +289,28 @@ impl ContrastPalette {
         }
     }
 
+    /// Get a [Palette] with a bright background and dim text, foreground and border.
+    #[must_use]
+    pub fn light(self) -> Palette {
+        Palette {
+            border: self.dim,
+            background: self.bright,
+            foreground: self.dim,
+            text: self.dim,
+        }
+    }
+
+    /// Get a [Palette] with a dim background and bright text, foreground and border.
+    #[must_use]
+    pub fn dark(self) -> Palette {
+        Palette {
+            border: self.bright,
+            background: self.dim,
+            foreground: self.bright,
+            text: self.bright,
+        }
+    }
+
     /// Swap the dim and bright fields.
     #[must_use]
     pub fn invert(self) -> Self {
@@ -96,9 +322,103 @@ impl ContrastPalette {
     }
 }
 
-fn lerp(a: f32, b: f32, t: f32) -> f32 {
-    let [a, b, t] = [a, b, t].map(|n| n.clamp(0.0, 1.0));
-    (a + t * (b - a)).clamp(0.0, 1.0)
+/// Palettes generated from a single accent color, with enough contrast for
+/// [`ContrastLevel::AA`] in the light, dark and muted dark variants.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AccentPalette {
+    /// Base palette, near white and near black tinted by the accent.
+    pub palette: ContrastPalette,
+    /// Alt palette, light and dark tones of the accent.
+    pub palette_alt: ContrastPalette,
+    /// Amount dim colors are muted by, at most the requested amount.
+    pub mute: f32,
+}
+
+impl AccentPalette {
+    /// Amount of steps taken towards black and white when contrast is too low.
+    const STEPS: u16 = 20;
+
+    /// Generate palettes from an accent color, muting dim colors by mute if contrast allows.
+    ///
+    /// Lightness starts at fixed values with the hue of the accent, and is moved towards black
+    /// and white, with chroma and mute reduced, until both variants pass. The last step is
+    /// monochrome without muting, which passes as long as [`ContrastPalette::success`] and
+    /// [`ContrastPalette::danger`] do.
+    #[must_use]
+    pub fn new(accent: Color, mute: f32) -> Self {
+        let accent = Oklab::from(accent);
+        let tone = |l: f32, chroma: f32| -> Color {
+            Oklab {
+                l,
+                a: accent.a * chroma,
+                b: accent.b * chroma,
+                alpha: 1.0,
+            }
+            .into()
+        };
+        let generate = |t: f32| {
+            let keep = 1.0 - t;
+            Self {
+                palette: ContrastPalette {
+                    bright: tone(1.0 - 0.03 * keep, 0.1 * keep),
+                    dim: tone(0.2 * keep, 0.1 * keep),
+                },
+                palette_alt: ContrastPalette {
+                    bright: tone(1.0 - 0.12 * keep, keep),
+                    dim: tone(0.3 * keep, 0.6 * keep),
+                },
+                mute: mute.clamp(0.0, 1.0) * keep,
+            }
+        };
+
+        (0..Self::STEPS)
+            .map(|step| generate(f32::from(step) / f32::from(Self::STEPS)))
+            .find(|generated| {
+                [generated.light(), generated.dark(), generated.dark_mute()]
+                    .iter()
+                    .all(|palette| palette.validate(ContrastLevel::AA).is_ok())
+            })
+            .unwrap_or_else(|| generate(1.0))
+    }
+
+    /// Get the [`ThemePalette`] of the light variant.
+    #[must_use]
+    pub fn light(&self) -> ThemePalette {
+        ThemePalette::derive(
+            self.palette,
+            self.palette_alt,
+            ContrastPalette::success(),
+            ContrastPalette::danger(),
+            Some(self.mute),
+            ContrastPalette::light,
+        )
+    }
+
+    /// Get the [`ThemePalette`] of the dark variant.
+    #[must_use]
+    pub fn dark(&self) -> ThemePalette {
+        ThemePalette::derive(
+            self.palette,
+            self.palette_alt,
+            ContrastPalette::success(),
+            ContrastPalette::danger(),
+            Some(self.mute),
+            ContrastPalette::dark,
+        )
+    }
+
+    /// Get the [`ThemePalette`] of the muted dark variant, which also mutes the dim background.
+    #[must_use]
+    pub fn dark_mute(&self) -> ThemePalette {
+        ThemePalette::derive(
+            self.palette,
+            self.palette_alt,
+            ContrastPalette::success(),
+            ContrastPalette::danger(),
+            Some(self.mute),
+            |palette| palette.mute_dim(Some(self.mute)).dark(),
+        )
+    }
 }
 
 impl Default for ContrastPalette {
@@ -107,47 +427,272 @@ impl Default for ContrastPalette {
     }
 }
 
+/// A color in linear RGB, with channels 0..1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearRgb {
+    /// Red channel.
+    pub r: f32,
+    /// Green channel.
+    pub g: f32,
+    /// Blue channel.
+    pub b: f32,
+    /// Alpha channel.
+    pub a: f32,
+}
+
+/// A color in HSL, with hue in degrees 0..360 and saturation and lightness 0..1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsl {
+    /// Hue in degrees.
+    pub h: f32,
+    /// Saturation.
+    pub s: f32,
+    /// Lightness.
+    pub l: f32,
+    /// Alpha channel.
+    pub a: f32,
+}
+
+/// A color in the `OKLab` perceptual color space, with lightness 0..1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklab {
+    /// Perceived lightness.
+    pub l: f32,
+    /// Green/red axis.
+    pub a: f32,
+    /// Blue/yellow axis.
+    pub b: f32,
+    /// Alpha channel.
+    pub alpha: f32,
+}
+
+fn to_linear(c: f32) -> f32 {
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn from_linear(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+impl From<Color> for LinearRgb {
+    fn from(Color { r, g, b, a }: Color) -> Self {
+        Self {
+            r: to_linear(r),
+            g: to_linear(g),
+            b: to_linear(b),
+            a,
+        }
+    }
+}
+
+impl From<LinearRgb> for Color {
+    fn from(LinearRgb { r, g, b, a }: LinearRgb) -> Self {
+        Color {
+            r: from_linear(r).clamp(0.0, 1.0),
+            g: from_linear(g).clamp(0.0, 1.0),
+            b: from_linear(b).clamp(0.0, 1.0),
+            a,
+        }
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(Color { r, g, b, a }: Color) -> Self {
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = 0.5 * (max + min);
+        let d = max - min;
+        if d <= f32::EPSILON {
+            return Self {
+                h: 0.0,
+                s: 0.0,
+                l,
+                a,
+            };
+        }
+        let s = d / (1.0 - (2.0 * l - 1.0).abs());
+        #[allow(clippy::float_cmp)]
+        let h = if max == r {
+            ((g - b) / d).rem_euclid(6.0)
+        } else if max == g {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        } * 60.0;
+        Self { h, s, l, a }
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(Hsl { h, s, l, a }: Hsl) -> Self {
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let sector = h.rem_euclid(360.0) / 60.0;
+        let second = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+        let (r, g, b) = match sector {
+            sector if sector < 1.0 => (chroma, second, 0.0),
+            sector if sector < 2.0 => (second, chroma, 0.0),
+            sector if sector < 3.0 => (0.0, chroma, second),
+            sector if sector < 4.0 => (0.0, second, chroma),
+            sector if sector < 5.0 => (second, 0.0, chroma),
+            _ => (chroma, 0.0, second),
+        };
+        let offset = l - chroma / 2.0;
+        Color {
+            r: (r + offset).clamp(0.0, 1.0),
+            g: (g + offset).clamp(0.0, 1.0),
+            b: (b + offset).clamp(0.0, 1.0),
+            a,
+        }
+    }
+}
+
+impl From<LinearRgb> for Oklab {
+    fn from(LinearRgb { r, g, b, a }: LinearRgb) -> Self {
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        Self {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            alpha: a,
+        }
+    }
+}
+
+impl From<Oklab> for LinearRgb {
+    fn from(Oklab { l, a, b, alpha }: Oklab) -> Self {
+        let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+        let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+        let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+        let (l, m, s) = (l_.powi(3), m_.powi(3), s_.powi(3));
+        Self {
+            r: 4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            g: -1.268_438 * l + 2.609_757_4 * m - 0.341_319_4 * s,
+            b: -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+            a: alpha,
+        }
+    }
+}
+
+impl From<Color> for Oklab {
+    fn from(color: Color) -> Self {
+        LinearRgb::from(color).into()
+    }
+}
+
+impl From<Oklab> for Color {
+    fn from(color: Oklab) -> Self {
+        LinearRgb::from(color).into()
+    }
+}
+
+impl Oklab {
+    /// Get the chroma, the distance from grey.
+    #[must_use]
+    pub fn chroma(self) -> f32 {
+        self.a.hypot(self.b)
+    }
+
+    /// Lerp between two colors.
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: f32, b: f32| a + t * (b - a);
+        Self {
+            l: mix(self.l, other.l),
+            a: mix(self.a, other.a),
+            b: mix(self.b, other.b),
+            alpha: mix(self.alpha, other.alpha),
+        }
+    }
+}
+
 /// Trait to extend [Color][iced::Color] with some methods.
 #[allow(clippy::module_name_repetitions)]
 pub trait ColorManipExt {
-    /// Mute the if given by amount t 0..1.
+    /// Mute the color towards grey in `OKLab` by given amount t 0..1, or a default amount.
     #[must_use]
     fn mute(self, t: Option<f32>) -> Self;
 
-    /// Lerp between two colors.
+    /// Lerp between two colors in `OKLab`.
     #[must_use]
     fn lerp(self, other: Self, t: f32) -> Self;
+
+    /// Move perceived lightness towards white by amount t 0..1.
+    #[must_use]
+    fn lighten(self, t: f32) -> Self;
+
+    /// Move perceived lightness towards black by amount t 0..1.
+    #[must_use]
+    fn darken(self, t: f32) -> Self;
+
+    /// Scale chroma by 1 + t, negative t desaturates.
+    #[must_use]
+    fn saturate(self, t: f32) -> Self;
+
+    /// Get the WCAG relative luminance of a color 0..1, ignoring alpha.
+    #[must_use]
+    fn luminance(self) -> f32;
+
+    /// Get the WCAG contrast ratio between two colors 1..21, ignoring alpha.
+    #[must_use]
+    fn contrast_ratio(self, other: Self) -> f32;
 }
 
 impl ColorManipExt for Color {
     fn lerp(self, other: Self, t: f32) -> Self {
-        let Color {
-            r: r1,
-            g: g1,
-            b: b1,
-            a: a1,
-        } = self;
-        let Color {
-            r: r2,
-            g: g2,
-            b: b2,
-            a: a2,
-        } = other;
-        Color {
-            r: lerp(r1, r2, t),
-            g: lerp(g1, g2, t),
-            b: lerp(b1, b2, t),
-            a: lerp(a1, a2, t),
-        }
+        Oklab::from(self).lerp(other.into(), t).into()
     }
     fn mute(self, t: Option<f32>) -> Color {
-        let Color { r, g, b, a } = self;
-        let t = t.unwrap_or(0.25);
-        Color {
-            r: lerp(r, 0.5, t),
-            g: lerp(g, 0.5, t),
-            b: lerp(b, 0.5, t),
-            a,
+        let grey = Color {
+            r: 0.5,
+            g: 0.5,
+            b: 0.5,
+            a: self.a,
+        };
+        self.lerp(grey, t.unwrap_or(0.25))
+    }
+    fn lighten(self, t: f32) -> Self {
+        let color = Oklab::from(self);
+        Oklab {
+            l: color.l + t.clamp(0.0, 1.0) * (1.0 - color.l),
+            ..color
+        }
+        .into()
+    }
+    fn darken(self, t: f32) -> Self {
+        let color = Oklab::from(self);
+        Oklab {
+            l: color.l * (1.0 - t.clamp(0.0, 1.0)),
+            ..color
         }
+        .into()
+    }
+    fn saturate(self, t: f32) -> Self {
+        let color = Oklab::from(self);
+        let scale = (1.0 + t).max(0.0);
+        Oklab {
+            a: color.a * scale,
+            b: color.b * scale,
+            ..color
+        }
+        .into()
+    }
+    fn luminance(self) -> f32 {
+        let LinearRgb { r, g, b, .. } = self.into();
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+    fn contrast_ratio(self, other: Self) -> f32 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
     }
 }
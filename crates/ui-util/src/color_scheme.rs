@@ -0,0 +1,191 @@
+//! Desktop light/dark preference, read from the freedesktop settings portal with a fallback to
+//! the environment.
+
+use crate::Theme;
+use iced::{
+    futures::{
+        future,
+        stream::{BoxStream, StreamExt},
+    },
+    subscription, Subscription,
+};
+use portal::SettingsProxy;
+use std::env;
+use zbus::{zvariant::Value, Connection};
+
+/// Settings namespace holding the color scheme.
+const NAMESPACE: &str = "org.freedesktop.appearance";
+
+/// Settings key of the color scheme.
+const KEY: &str = "color-scheme";
+
+/// Desktop color scheme preference.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorScheme {
+    /// No preference is known.
+    #[default]
+    NoPreference,
+    /// The desktop prefers dark themes.
+    Dark,
+    /// The desktop prefers light themes.
+    Light,
+}
+
+impl ColorScheme {
+    /// Get the built in theme matching the preference, [`Theme::Light`] without one.
+    #[must_use]
+    pub fn theme(self) -> Theme {
+        match self {
+            ColorScheme::Dark => Theme::Dark,
+            ColorScheme::Light | ColorScheme::NoPreference => Theme::Light,
+        }
+    }
+
+    /// Read a color scheme from a portal setting value, which may be wrapped in variants.
+    #[must_use]
+    pub fn from_value(value: &Value) -> Self {
+        match value {
+            Value::Value(inner) => Self::from_value(inner),
+            Value::U32(1) => ColorScheme::Dark,
+            Value::U32(2) => ColorScheme::Light,
+            _ => ColorScheme::NoPreference,
+        }
+    }
+
+    /// Read a color scheme from the `GTK_THEME` environment variable, see
+    /// [`ColorScheme::from_gtk_theme`].
+    #[must_use]
+    pub fn from_env() -> Self {
+        env::var("GTK_THEME").map_or(ColorScheme::NoPreference, |name| {
+            Self::from_gtk_theme(&name)
+        })
+    }
+
+    /// Keep a known preference, else read one from the environment with
+    /// [`ColorScheme::from_env`].
+    #[must_use]
+    pub fn or_env(self) -> Self {
+        match self {
+            ColorScheme::NoPreference => Self::from_env(),
+            scheme => scheme,
+        }
+    }
+
+    /// Read a color scheme from a gtk theme name, such as `Adwaita:dark`. Names containing
+    /// `dark` are a dark preference, other names a light one.
+    #[must_use]
+    pub fn from_gtk_theme(name: &str) -> Self {
+        if name.is_empty() {
+            ColorScheme::NoPreference
+        } else if name.to_lowercase().contains("dark") {
+            ColorScheme::Dark
+        } else {
+            ColorScheme::Light
+        }
+    }
+}
+
+/// Proxy for the settings interface of the desktop portal, generated items are not documented.
+#[allow(missing_docs)]
+mod portal {
+    use zbus::{dbus_proxy, zvariant::Value};
+
+    /// Proxy for the settings interface of the desktop portal.
+    #[dbus_proxy(
+        interface = "org.freedesktop.portal.Settings",
+        default_service = "org.freedesktop.portal.Desktop",
+        default_path = "/org/freedesktop/portal/desktop"
+    )]
+    trait Settings {
+        /// Read a single setting.
+        fn read(&self, namespace: &str, key: &str) -> zbus::Result<zbus::zvariant::OwnedValue>;
+
+        /// Signal a setting has changed.
+        #[dbus_proxy(signal)]
+        fn setting_changed(&self, namespace: &str, key: &str, value: Value<'_>)
+            -> zbus::Result<()>;
+    }
+}
+
+/// Read the color scheme from the settings portal reachable over a connection.
+///
+/// # Errors
+/// If the portal cannot be reached or does not have the setting.
+pub async fn read_with(connection: &Connection) -> zbus::Result<ColorScheme> {
+    let proxy = SettingsProxy::new(connection).await?;
+    let value = proxy.read(NAMESPACE, KEY).await?;
+    Ok(ColorScheme::from_value(&value))
+}
+
+/// Get a stream of color scheme changes from the settings portal reachable over a connection.
+///
+/// # Errors
+/// If the portal cannot be reached.
+pub async fn changes_with(
+    connection: &Connection,
+) -> zbus::Result<BoxStream<'static, ColorScheme>> {
+    let proxy = SettingsProxy::new(connection).await?;
+    let stream = proxy.receive_setting_changed().await?;
+    Ok(stream
+        .filter_map(|signal| async move {
+            let args = signal.args().ok()?;
+            (*args.namespace() == NAMESPACE && *args.key() == KEY)
+                .then(|| ColorScheme::from_value(args.value()))
+        })
+        .boxed())
+}
+
+/// Read the color scheme of the desktop, from the settings portal on the session bus if it has
+/// a preference, else from the environment.
+pub async fn read() -> ColorScheme {
+    match Connection::session().await {
+        Ok(connection) => read_or_env(&connection).await,
+        Err(_) => ColorScheme::from_env(),
+    }
+}
+
+/// Read the color scheme from the settings portal reachable over a connection if it has a
+/// preference, else from the environment.
+async fn read_or_env(connection: &Connection) -> ColorScheme {
+    read_with(connection)
+        .await
+        .unwrap_or(ColorScheme::NoPreference)
+        .or_env()
+}
+
+/// Watch the color scheme of the desktop, reading it once at the start and again whenever the
+/// settings portal reports a change, falling back to the environment whenever the portal has no
+/// preference. Without a portal only the initial value is produced.
+#[must_use]
+pub fn watch() -> Subscription<ColorScheme> {
+    enum State {
+        Start,
+        Watching(BoxStream<'static, ColorScheme>),
+        Done,
+    }
+
+    #[derive(Hash)]
+    struct Watch;
+
+    subscription::unfold(Watch, State::Start, |state| async move {
+        match state {
+            State::Start => match Connection::session().await {
+                Ok(connection) => {
+                    // Listen before reading, so a change in between is not missed.
+                    let next = changes_with(&connection)
+                        .await
+                        .map_or(State::Done, |changes| {
+                            State::Watching(changes.map(ColorScheme::or_env).boxed())
+                        });
+                    (Some(read_or_env(&connection).await), next)
+                }
+                Err(_) => (Some(ColorScheme::from_env()), State::Done),
+            },
+            State::Watching(mut changes) => match changes.next().await {
+                Some(scheme) => (Some(scheme), State::Watching(changes)),
+                None => (None, State::Done),
+            },
+            State::Done => future::pending().await,
+        }
+    })
+}
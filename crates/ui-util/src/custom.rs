@@ -0,0 +1,240 @@
+//! User defined themes loaded from configuration files.
+
+use crate::{color::AccentPalette, ContrastPalette, Theme};
+use iced::{subscription, Color, Subscription};
+use serde::{de, Deserialize, Deserializer};
+use std::{
+    io,
+    path::{Path, PathBuf},
+    result,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+use thiserror::Error;
+use tokio::{fs, time};
+
+/// How often a watched theme file is checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Error type for loading custom themes.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Forward for IO errors.
+    #[error(transparent)]
+    IO(#[from] io::Error),
+    /// Forward for toml deserialization errors.
+    #[error(transparent)]
+    Toml(#[from] toml::de::Error),
+}
+
+/// Result type for loading custom themes.
+pub type Result<T = ()> = result::Result<T, Error>;
+
+/// Which of the built in themes a [`CustomTheme`] converts its palettes like.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Base {
+    /// Bright background with dim text, like [`Theme::Light`].
+    #[default]
+    Light,
+    /// Dim background with bright text, like [`Theme::Dark`].
+    Dark,
+    /// Muted dim background with bright text, like [`Theme::DarkMute`].
+    DarkMute,
+}
+
+/// A theme defined by the user, see the documentation of the fields for the toml keys.
+///
+/// Colors are given as `"#rrggbb"` or `"#rrggbbaa"` strings. Instead of the palettes a single
+/// `accent` color may be given, which they are generated from by [`AccentPalette`], with `mute`
+/// lowered if needed for contrast. Palettes given next to `accent` replace the generated ones, and
+/// when both are given `accent` is ignored.
+/// The success and danger palettes default to [`ContrastPalette::success`] and
+/// [`ContrastPalette::danger`].
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(try_from = "Definition")]
+pub struct CustomTheme {
+    /// Name shown for the theme.
+    pub name: String,
+    /// How palettes are converted.
+    pub base: Base,
+    /// Base palette of the theme, as the `[palette]` table with `bright` and `dim` keys.
+    pub palette: ContrastPalette,
+    /// Alt palette of the theme, as the `[palette_alt]` table with `bright` and `dim` keys.
+    pub palette_alt: ContrastPalette,
+    /// Success palette of the theme, as the `[palette_success]` table.
+    pub palette_success: ContrastPalette,
+    /// Danger palette of the theme, as the `[palette_danger]` table.
+    pub palette_danger: ContrastPalette,
+    /// Amount colors are muted by, 0..1.
+    pub mute: f32,
+    /// Border radius used by widgets.
+    pub border_radius: f32,
+    /// Border width used by widgets with borders.
+    pub border_width: f32,
+}
+
+/// Toml representation of a [`CustomTheme`].
+#[derive(Deserialize)]
+struct Definition {
+    name: String,
+    #[serde(default)]
+    base: Base,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    accent: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_palette")]
+    palette: Option<ContrastPalette>,
+    #[serde(default, deserialize_with = "deserialize_palette")]
+    palette_alt: Option<ContrastPalette>,
+    #[serde(default, deserialize_with = "deserialize_palette")]
+    palette_success: Option<ContrastPalette>,
+    #[serde(default, deserialize_with = "deserialize_palette")]
+    palette_danger: Option<ContrastPalette>,
+    #[serde(default = "default_mute")]
+    mute: f32,
+    #[serde(default)]
+    border_radius: f32,
+    #[serde(default = "default_border_width")]
+    border_width: f32,
+}
+
+impl TryFrom<Definition> for CustomTheme {
+    type Error = &'static str;
+
+    fn try_from(value: Definition) -> result::Result<Self, Self::Error> {
+        // Only generate what is not given, so mute is kept when both palettes are.
+        let generated = value
+            .accent
+            .filter(|_| value.palette.is_none() || value.palette_alt.is_none())
+            .map(|accent| AccentPalette::new(accent, value.mute));
+        let (Some(palette), Some(palette_alt)) = (
+            value
+                .palette
+                .or(generated.map(|generated| generated.palette)),
+            value
+                .palette_alt
+                .or(generated.map(|generated| generated.palette_alt)),
+        ) else {
+            return Err("either accent or both palette and palette_alt are needed");
+        };
+        Ok(Self {
+            name: value.name,
+            base: value.base,
+            palette,
+            palette_alt,
+            palette_success: value
+                .palette_success
+                .unwrap_or_else(ContrastPalette::success),
+            palette_danger: value.palette_danger.unwrap_or_else(ContrastPalette::danger),
+            mute: generated.map_or(value.mute, |generated| generated.mute),
+            border_radius: value.border_radius,
+            border_width: value.border_width,
+        })
+    }
+}
+
+fn default_mute() -> f32 {
+    0.25
+}
+
+fn default_border_width() -> f32 {
+    1.0
+}
+
+/// Parse a color from a `"#rrggbb"` or `"#rrggbbaa"` string.
+fn parse_color(value: &str) -> Option<Color> {
+    let hex = value.strip_prefix('#')?;
+    if !matches!(hex.len(), 6 | 8) || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |index: usize| {
+        hex.get(index * 2..index * 2 + 2)
+            .map(|channel| u8::from_str_radix(channel, 16))
+    };
+    let r = channel(0)?.ok()?;
+    let g = channel(1)?.ok()?;
+    let b = channel(2)?.ok()?;
+    let a = channel(3).transpose().ok()?.unwrap_or(u8::MAX);
+    Some(Color::from_rgba8(r, g, b, f32::from(a) / 255.0))
+}
+
+fn color_error<E: de::Error>(value: &str) -> E {
+    de::Error::invalid_value(de::Unexpected::Str(value), &"a \"#rrggbb\" color")
+}
+
+fn deserialize_color<'de, D>(deserializer: D) -> result::Result<Option<Color>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    parse_color(&value)
+        .map(Some)
+        .ok_or_else(|| color_error(&value))
+}
+
+fn deserialize_palette<'de, D>(deserializer: D) -> result::Result<Option<ContrastPalette>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Definition {
+        bright: String,
+        dim: String,
+    }
+
+    let color = |value: String| -> result::Result<Color, D::Error> {
+        parse_color(&value).ok_or_else(|| color_error(&value))
+    };
+
+    let Definition { bright, dim } = Definition::deserialize(deserializer)?;
+    Ok(Some(ContrastPalette {
+        bright: color(bright)?,
+        dim: color(dim)?,
+    }))
+}
+
+impl CustomTheme {
+    /// Load a custom theme from a toml file.
+    ///
+    /// # Errors
+    /// If the file cannot be read or if it is wrongly formatted.
+    pub async fn load(path: PathBuf) -> Result<Self> {
+        tracing::debug!(path = %path.display(), "loading theme");
+        Ok(toml::from_str(&fs::read_to_string(path).await?)?)
+    }
+}
+
+impl From<CustomTheme> for Theme {
+    fn from(value: CustomTheme) -> Self {
+        Theme::Custom(Arc::new(value))
+    }
+}
+
+/// Get the modification time of a file, if it can be read.
+async fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).await.ok()?.modified().ok()
+}
+
+/// Watch a theme file, loading it once at the start and again whenever it changes.
+#[must_use]
+pub fn watch(path: PathBuf) -> Subscription<Result<CustomTheme>> {
+    #[derive(Hash)]
+    struct Watch(PathBuf);
+
+    subscription::unfold(
+        Watch(path.clone()),
+        (path, None),
+        |(path, last): (PathBuf, Option<Option<SystemTime>>)| async move {
+            if last.is_some() {
+                time::sleep(POLL_INTERVAL).await;
+            }
+            let current = modified(&path).await;
+            if last == Some(current) {
+                (None, (path, last))
+            } else {
+                let theme = CustomTheme::load(path.clone()).await;
+                (Some(theme), (path, Some(current)))
+            }
+        },
+    )
+}
@@ -0,0 +1,56 @@
+//! Bundled icons, drawn as black svg strokes to be tinted by the style of the svg widget.
+
+use iced::widget::svg;
+use tap::Pipe;
+
+/// An icon bundled with the crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Icon {
+    /// A plus sign.
+    Add,
+    /// A cross.
+    Close,
+    /// A check mark.
+    Check,
+    /// An open folder.
+    Open,
+    /// A floppy disk.
+    Save,
+    /// A trash can.
+    Delete,
+    /// A pencil.
+    Edit,
+    /// A magnifying glass.
+    Search,
+}
+
+impl Icon {
+    /// Get the svg elements drawing the icon on a 24 by 24 canvas.
+    fn body(self) -> &'static str {
+        match self {
+            Icon::Add => r#"<path d="M12 5v14M5 12h14"/>"#,
+            Icon::Close => r#"<path d="M6 6l12 12M18 6L6 18"/>"#,
+            Icon::Check => r#"<path d="M5 12l5 5 9-10"/>"#,
+            Icon::Open => r#"<path d="M3 6h6l2 2h10v11H3z"/>"#,
+            Icon::Save => r#"<path d="M5 3h11l3 3v15H5zM8 3v5h7V3M8 21v-7h8v7"/>"#,
+            Icon::Delete => r#"<path d="M4 7h16M9 7V4h6v3M6 7l1 14h10l1-14M10 11v6M14 11v6"/>"#,
+            Icon::Edit => r#"<path d="M4 20l1-4L16 5l3 3L8 19zM14 7l3 3"/>"#,
+            Icon::Search => r#"<circle cx="10" cy="10" r="6"/><path d="M15 15l5 5"/>"#,
+        }
+    }
+
+    /// Get an svg handle drawing the icon, tint it by setting the color of the svg style.
+    #[must_use]
+    pub fn handle(self) -> svg::Handle {
+        format!(
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" "#,
+                r#"stroke="black" stroke-width="2" stroke-linecap="round" "#,
+                r#"stroke-linejoin="round">{}</svg>"#,
+            ),
+            self.body(),
+        )
+        .into_bytes()
+        .pipe(svg::Handle::from_memory)
+    }
+}
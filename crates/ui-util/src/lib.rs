@@ -9,49 +9,61 @@
 )]
 
 use color::{ColorManipExt, ContrastPalette, Palette, ThemePalette};
+use custom::{Base, CustomTheme};
 use iced::{
     widget::{Column, Row},
-    Background, Color, Element,
+    Color, Element,
 };
+use std::{fmt::Display, mem, sync::Arc};
 use theme::Var;
 
+pub mod bookmark_row;
 pub mod color;
+pub mod color_scheme;
+pub mod custom;
+pub mod icon;
+pub mod modal;
+pub mod reorder;
+pub mod resize;
+pub mod table;
 pub mod tabs;
 pub mod text_button;
 pub mod theme;
+pub mod toast;
+pub mod virtual_list;
 
 /// Extension trait to create rows or columns from an iterator.
-pub trait IteratorWidgetExt<Message>: Iterator {
+pub trait IteratorWidgetExt<Message, Renderer>: Iterator {
     /// Collect an iterator into a column using the passed function to transform the iterator
     /// content to elements.
-    fn collect_column<'a, E, F>(self, f: F) -> Column<'a, Message>
+    fn collect_column<'a, E, F>(self, f: F) -> Column<'a, Message, Renderer>
     where
-        E: Into<Element<'a, Message>>,
+        E: Into<Element<'a, Message, Renderer>>,
         F: FnMut(Self::Item) -> E;
 
     /// Collect an iterator into a row using the passed function to transform the iterator content
     /// to elements
-    fn collect_row<'a, E, F>(self, f: F) -> Row<'a, Message>
+    fn collect_row<'a, E, F>(self, f: F) -> Row<'a, Message, Renderer>
     where
-        E: Into<Element<'a, Message>>,
+        E: Into<Element<'a, Message, Renderer>>,
         F: FnMut(Self::Item) -> E;
 }
 
-impl<I, Message> IteratorWidgetExt<Message> for I
+impl<I, Message, Renderer> IteratorWidgetExt<Message, Renderer> for I
 where
     I: Iterator,
 {
-    fn collect_row<'a, E, F>(self, mut f: F) -> Row<'a, Message>
+    fn collect_row<'a, E, F>(self, mut f: F) -> Row<'a, Message, Renderer>
     where
-        E: Into<Element<'a, Message>>,
+        E: Into<Element<'a, Message, Renderer>>,
         F: FnMut(Self::Item) -> E,
     {
         self.fold(Row::new(), |row, item| row.push(f(item)))
     }
 
-    fn collect_column<'a, E, F>(self, mut f: F) -> Column<'a, Message>
+    fn collect_column<'a, E, F>(self, mut f: F) -> Column<'a, Message, Renderer>
     where
-        E: Into<Element<'a, Message>>,
+        E: Into<Element<'a, Message, Renderer>>,
         F: FnMut(Self::Item) -> E,
     {
         self.fold(Column::new(), |column, item| column.push(f(item)))
@@ -62,7 +74,7 @@ where
 pub type Renderer = iced::Renderer<Theme>;
 
 /// Custom theme used for ui.
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub enum Theme {
     /// Light theme
     #[default]
@@ -71,63 +83,138 @@ pub enum Theme {
     Dark,
     /// Muted dark theme
     DarkMute,
+    /// User defined theme
+    Custom(Arc<CustomTheme>),
+}
+
+impl PartialEq for Theme {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Theme::Custom(a), Theme::Custom(b)) => Arc::ptr_eq(a, b) || a == b,
+            (a, b) => mem::discriminant(a) == mem::discriminant(b),
+        }
+    }
+}
+
+impl Eq for Theme {}
+
+impl Display for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Theme::Light => write!(f, "light"),
+            Theme::Dark => write!(f, "dark"),
+            Theme::DarkMute => write!(f, "dark mute"),
+            Theme::Custom(custom) => write!(f, "{}", custom.name),
+        }
+    }
 }
 
 impl Theme {
+    /// All available themes.
+    pub const ALL: [Theme; 3] = [Theme::Light, Theme::Dark, Theme::DarkMute];
+
     /// Get [`ContrastPalette`] representing current theme base.
     #[must_use]
     pub fn contrast_palette(&self) -> ContrastPalette {
-        ContrastPalette::monochrome()
+        match self {
+            Theme::Custom(custom) => custom.palette,
+            _ => ContrastPalette::monochrome(),
+        }
     }
 
     /// Get a [`ContrastPalette`] representing current theme alt.
     #[must_use]
     pub fn contrast_palette_alt(&self) -> ContrastPalette {
-        ContrastPalette {
-            bright: Color::from_rgb8(150, 200, 255),
-            dim: Color::from_rgb8(0, 0, 40),
+        match self {
+            Theme::Custom(custom) => custom.palette_alt,
+            _ => ContrastPalette {
+                bright: Color::from_rgb8(150, 200, 255),
+                dim: Color::from_rgb8(0, 0, 40),
+            },
+        }
+    }
+
+    /// Get a [`ContrastPalette`] representing current theme success.
+    #[must_use]
+    pub fn contrast_palette_success(&self) -> ContrastPalette {
+        match self {
+            Theme::Custom(custom) => custom.palette_success,
+            _ => ContrastPalette::success(),
+        }
+    }
+
+    /// Get a [`ContrastPalette`] representing current theme danger.
+    #[must_use]
+    pub fn contrast_palette_danger(&self) -> ContrastPalette {
+        match self {
+            Theme::Custom(custom) => custom.palette_danger,
+            _ => ContrastPalette::danger(),
+        }
+    }
+
+    /// Get the amount colors are muted by, [None] for the default amount.
+    #[must_use]
+    pub fn mute_amount(&self) -> Option<f32> {
+        match self {
+            Theme::Custom(custom) => Some(custom.mute),
+            _ => None,
         }
     }
+
+    /// Get the [`Base`] palettes are converted by.
+    #[must_use]
+    pub fn base(&self) -> Base {
+        match self {
+            Theme::Light => Base::Light,
+            Theme::Dark => Base::Dark,
+            Theme::DarkMute => Base::DarkMute,
+            Theme::Custom(custom) => custom.base,
+        }
+    }
+
     /// Get a [`ThemePalette`] representing the current theme.
     #[must_use]
     pub fn theme_palette(&self) -> ThemePalette {
-        ThemePalette {
-            mute: self.convert_palette(self.contrast_palette().mute_dim(None)),
-            alt: self.convert_palette(self.contrast_palette_alt().mute_dim(None)),
-            mute_highlight: self.convert_palette(self.contrast_palette()),
-            alt_highlight: self.convert_palette(self.contrast_palette_alt()),
-        }
+        ThemePalette::derive(
+            self.contrast_palette(),
+            self.contrast_palette_alt(),
+            self.contrast_palette_success(),
+            self.contrast_palette_danger(),
+            self.mute_amount(),
+            |palette| self.convert_palette(palette),
+        )
     }
 
     /// Get a [Palette] from a [`ContrastPalette`] using current theme.
     #[must_use]
-    pub fn convert_palette(&self, ContrastPalette { bright, dim }: ContrastPalette) -> Palette {
-        match self {
-            Theme::Light => Palette {
-                border: dim,
-                background: bright,
-                foreground: dim,
-                text: dim,
-            },
-            Theme::Dark => Palette {
-                border: bright,
-                background: dim,
-                foreground: bright,
-                text: bright,
-            },
-            Theme::DarkMute => Palette {
-                border: bright,
-                background: dim.mute(None),
-                foreground: bright,
-                text: bright,
-            },
+    pub fn convert_palette(&self, palette: ContrastPalette) -> Palette {
+        match self.base() {
+            Base::Light => palette.light(),
+            Base::Dark => palette.dark(),
+            Base::DarkMute => ContrastPalette {
+                dim: palette.dim.mute(self.mute_amount()),
+                ..palette
+            }
+            .dark(),
         }
     }
 
     /// Get the border radius in use.
     #[must_use]
     pub fn border_radius(&self) -> f32 {
-        0.0
+        match self {
+            Theme::Custom(custom) => custom.border_radius,
+            _ => 0.0,
+        }
+    }
+
+    /// Get the border width in use by widgets with borders.
+    #[must_use]
+    pub fn border_width(&self) -> f32 {
+        match self {
+            Theme::Custom(custom) => custom.border_width,
+            _ => 1.0,
+        }
     }
 }
 
@@ -149,7 +236,7 @@ impl iced::application::StyleSheet for Theme {
             theme::Application::ContrastPalette(palette) => {
                 let Palette {
                     background, text, ..
-                } = self.convert_palette(palette.mute_dim(None));
+                } = self.convert_palette(palette.mute_dim(self.mute_amount()));
                 Appearance {
                     background_color: background,
                     text_color: text,
@@ -168,8 +255,10 @@ impl iced::widget::text::StyleSheet for Theme {
         Appearance {
             color: style.map(|style| match style {
                 theme::Text::Theme => self.theme_palette().mute.text,
+                theme::Text::Dim => self.theme_palette().mute.text.mute(Some(0.5)),
                 theme::Text::ContrastPalette(palette) => {
-                    self.convert_palette(palette.mute_dim(None)).text
+                    self.convert_palette(palette.mute_dim(self.mute_amount()))
+                        .text
                 }
                 theme::Text::Color(color) => color,
             }),
@@ -177,6 +266,25 @@ impl iced::widget::text::StyleSheet for Theme {
     }
 }
 
+fn container_box_appearance(
+    Palette {
+        border,
+        background,
+        text,
+        ..
+    }: Palette,
+    border_radius: f32,
+    border_width: f32,
+) -> iced::widget::container::Appearance {
+    iced::widget::container::Appearance {
+        text_color: Some(text),
+        background: Some(background.into()),
+        border_radius,
+        border_width,
+        border_color: border,
+    }
+}
+
 impl iced::widget::container::StyleSheet for Theme {
     type Style = Option<theme::Container>;
 
@@ -191,21 +299,21 @@ impl iced::widget::container::StyleSheet for Theme {
                 border_color: Color::BLACK,
             },
             |style| match style {
-                theme::Container::Theme(Var::Alt) => {
-                    let Palette {
-                        border,
-                        background,
-                        text,
-                        ..
-                    } = self.theme_palette().alt;
-                    Appearance {
-                        text_color: Some(text),
-                        background: Some(background.into()),
-                        border_radius: self.border_radius(),
-                        border_width: 1.0,
-                        border_color: border,
-                    }
-                }
+                theme::Container::Theme(Var::Alt) => container_box_appearance(
+                    self.theme_palette().alt,
+                    self.border_radius(),
+                    self.border_width(),
+                ),
+                theme::Container::Success => container_box_appearance(
+                    self.theme_palette().success,
+                    self.border_radius(),
+                    self.border_width(),
+                ),
+                theme::Container::Danger => container_box_appearance(
+                    self.theme_palette().danger,
+                    self.border_radius(),
+                    self.border_width(),
+                ),
                 theme::Container::Theme(Var::Std) => {
                     let Palette {
                         background,
@@ -226,12 +334,16 @@ impl iced::widget::container::StyleSheet for Theme {
                         text,
                         border,
                         ..
-                    } = self.convert_palette(palette.mute_dim(None));
+                    } = self.convert_palette(palette.mute_dim(self.mute_amount()));
                     Appearance {
                         text_color: Some(text),
                         background: Some(background.into()),
                         border_radius: self.border_radius(),
-                        border_width: if matches!(var, Var::Std) { 0.0 } else { 1.0 },
+                        border_width: if matches!(var, Var::Std) {
+                            0.0
+                        } else {
+                            self.border_width()
+                        },
                         border_color: border,
                     }
                 }
@@ -304,7 +416,7 @@ fn button_appearance(
     border_radius: f32,
 ) -> iced::widget::button::Appearance {
     iced::widget::button::Appearance {
-        background: Some(foreground).map(Background::from),
+        background: Some(foreground.into()),
         border_radius,
         border_width: 0.0,
         border_color: Color::BLACK,
@@ -321,11 +433,12 @@ fn button_alt_appearance(
         ..
     }: Palette,
     border_radius: f32,
+    border_width: f32,
 ) -> iced::widget::button::Appearance {
     iced::widget::button::Appearance {
-        background: Some(background).map(Background::from),
+        background: Some(background.into()),
         border_radius,
-        border_width: 1.0,
+        border_width,
         border_color: foreground,
         text_color: text,
         ..Default::default()
@@ -341,8 +454,16 @@ impl iced::widget::button::StyleSheet for Theme {
             theme::Button::Theme(Var::Std) => {
                 button_appearance(self.theme_palette().mute, self.border_radius())
             }
-            theme::Button::Theme(Var::Alt) => {
-                button_alt_appearance(self.theme_palette().mute, self.border_radius())
+            theme::Button::Theme(Var::Alt) => button_alt_appearance(
+                self.theme_palette().mute,
+                self.border_radius(),
+                self.border_width(),
+            ),
+            theme::Button::Success => {
+                button_appearance(self.theme_palette().success, self.border_radius())
+            }
+            theme::Button::Danger => {
+                button_appearance(self.theme_palette().danger, self.border_radius())
             }
         }
     }
@@ -353,8 +474,16 @@ impl iced::widget::button::StyleSheet for Theme {
             theme::Button::Theme(Var::Std) => {
                 button_appearance(self.theme_palette().mute_highlight, self.border_radius())
             }
-            theme::Button::Theme(Var::Alt) => {
-                button_alt_appearance(self.theme_palette().mute_highlight, self.border_radius())
+            theme::Button::Theme(Var::Alt) => button_alt_appearance(
+                self.theme_palette().mute_highlight,
+                self.border_radius(),
+                self.border_width(),
+            ),
+            theme::Button::Success => {
+                button_appearance(self.theme_palette().success_highlight, self.border_radius())
+            }
+            theme::Button::Danger => {
+                button_appearance(self.theme_palette().danger_highlight, self.border_radius())
             }
         }
     }
@@ -365,8 +494,16 @@ impl iced::widget::button::StyleSheet for Theme {
             theme::Button::Theme(Var::Std) => {
                 button_appearance(self.theme_palette().mute, self.border_radius())
             }
-            theme::Button::Theme(Var::Alt) => {
-                button_alt_appearance(self.theme_palette().mute, self.border_radius())
+            theme::Button::Theme(Var::Alt) => button_alt_appearance(
+                self.theme_palette().mute,
+                self.border_radius(),
+                self.border_width(),
+            ),
+            theme::Button::Success => {
+                button_appearance(self.theme_palette().success, self.border_radius())
+            }
+            theme::Button::Danger => {
+                button_appearance(self.theme_palette().danger, self.border_radius())
             }
         }
     }
@@ -374,12 +511,601 @@ impl iced::widget::button::StyleSheet for Theme {
     fn disabled(&self, style: &Self::Style) -> iced::widget::button::Appearance {
         match style {
             theme::Button::Custom(style_sheet) => style_sheet.disabled(self),
-            theme::Button::Theme(Var::Std) => {
-                button_appearance(self.theme_palette().mute.mute(None), self.border_radius())
+            theme::Button::Theme(Var::Std) => button_appearance(
+                self.theme_palette().mute.mute(self.mute_amount()),
+                self.border_radius(),
+            ),
+            theme::Button::Theme(Var::Alt) => button_alt_appearance(
+                self.theme_palette().mute.mute(self.mute_amount()),
+                self.border_radius(),
+                self.border_width(),
+            ),
+            theme::Button::Success => button_appearance(
+                self.theme_palette().success.mute(self.mute_amount()),
+                self.border_radius(),
+            ),
+            theme::Button::Danger => button_appearance(
+                self.theme_palette().danger.mute(self.mute_amount()),
+                self.border_radius(),
+            ),
+        }
+    }
+}
+
+impl iced::widget::svg::StyleSheet for Theme {
+    type Style = theme::Svg;
+
+    fn appearance(&self, style: &Self::Style) -> iced::widget::svg::Appearance {
+        let color = match style {
+            theme::Svg::Custom(style_sheet) => return style_sheet.appearance(self),
+            theme::Svg::Theme(var) => self.var_palette(*var).0.text,
+            theme::Svg::Button(style) => {
+                iced::widget::button::StyleSheet::active(self, style).text_color
             }
-            theme::Button::Theme(Var::Alt) => {
-                button_alt_appearance(self.theme_palette().mute.mute(None), self.border_radius())
+        };
+        iced::widget::svg::Appearance { color: Some(color) }
+    }
+}
+
+impl Theme {
+    /// Get the [Palette] and highlight [Palette] used by a [Var].
+    fn var_palette(&self, var: Var) -> (Palette, Palette) {
+        let palette = self.theme_palette();
+        match var {
+            Var::Std => (palette.mute, palette.mute_highlight),
+            Var::Alt => (palette.alt, palette.alt_highlight),
+        }
+    }
+
+    /// Get the [Palette] and highlight [Palette] of a [`ContrastPalette`] using current theme.
+    fn contrast_var_palette(&self, palette: ContrastPalette) -> (Palette, Palette) {
+        (
+            self.convert_palette(palette.mute_dim(self.mute_amount())),
+            self.convert_palette(palette),
+        )
+    }
+}
+
+fn scrollbar_appearance(
+    Palette {
+        border,
+        background,
+        foreground,
+        ..
+    }: Palette,
+    border_radius: f32,
+) -> iced::widget::scrollable::Scrollbar {
+    iced::widget::scrollable::Scrollbar {
+        background: Some(background.into()),
+        border_radius,
+        border_width: 0.0,
+        border_color: border,
+        scroller: iced::widget::scrollable::Scroller {
+            color: foreground,
+            border_radius,
+            border_width: 0.0,
+            border_color: border,
+        },
+    }
+}
+
+impl iced::widget::scrollable::StyleSheet for Theme {
+    type Style = theme::Scrollable;
+
+    fn active(&self, style: &Self::Style) -> iced::widget::scrollable::Scrollbar {
+        match style {
+            theme::Scrollable::Custom(style_sheet) => style_sheet.active(self),
+            theme::Scrollable::Theme(var) => scrollbar_appearance(
+                self.var_palette(*var).0.mute(self.mute_amount()),
+                self.border_radius(),
+            ),
+            theme::Scrollable::ContrastPalette(palette) => scrollbar_appearance(
+                self.contrast_var_palette(*palette)
+                    .0
+                    .mute(self.mute_amount()),
+                self.border_radius(),
+            ),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> iced::widget::scrollable::Scrollbar {
+        match style {
+            theme::Scrollable::Custom(style_sheet) => style_sheet.hovered(self),
+            theme::Scrollable::Theme(var) => {
+                scrollbar_appearance(self.var_palette(*var).1, self.border_radius())
+            }
+            theme::Scrollable::ContrastPalette(palette) => {
+                scrollbar_appearance(self.contrast_var_palette(*palette).1, self.border_radius())
+            }
+        }
+    }
+
+    fn dragging(&self, style: &Self::Style) -> iced::widget::scrollable::Scrollbar {
+        match style {
+            theme::Scrollable::Custom(style_sheet) => style_sheet.dragging(self),
+            theme::Scrollable::Theme(var) => {
+                scrollbar_appearance(self.var_palette(*var).0, self.border_radius())
+            }
+            theme::Scrollable::ContrastPalette(palette) => {
+                scrollbar_appearance(self.contrast_var_palette(*palette).0, self.border_radius())
             }
         }
     }
 }
+
+fn pick_list_appearance(
+    Palette {
+        border,
+        background,
+        foreground,
+        text,
+    }: Palette,
+    border_radius: f32,
+    border_width: f32,
+) -> iced::widget::pick_list::Appearance {
+    iced::widget::pick_list::Appearance {
+        text_color: text,
+        placeholder_color: text.mute(None),
+        handle_color: foreground,
+        background: background.into(),
+        border_radius,
+        border_width,
+        border_color: border,
+    }
+}
+
+impl iced::widget::pick_list::StyleSheet for Theme {
+    type Style = theme::PickList;
+
+    fn active(&self, style: &Self::Style) -> iced::widget::pick_list::Appearance {
+        match style {
+            theme::PickList::Custom(style_sheet, _) => style_sheet.active(self),
+            theme::PickList::Theme(var) => pick_list_appearance(
+                self.var_palette(*var).0,
+                self.border_radius(),
+                self.border_width(),
+            ),
+            theme::PickList::ContrastPalette(palette) => pick_list_appearance(
+                self.contrast_var_palette(*palette).0,
+                self.border_radius(),
+                self.border_width(),
+            ),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> iced::widget::pick_list::Appearance {
+        match style {
+            theme::PickList::Custom(style_sheet, _) => style_sheet.hovered(self),
+            theme::PickList::Theme(var) => pick_list_appearance(
+                self.var_palette(*var).1,
+                self.border_radius(),
+                self.border_width(),
+            ),
+            theme::PickList::ContrastPalette(palette) => pick_list_appearance(
+                self.contrast_var_palette(*palette).1,
+                self.border_radius(),
+                self.border_width(),
+            ),
+        }
+    }
+}
+
+fn menu_appearance(
+    (
+        Palette {
+            border,
+            background,
+            text,
+            ..
+        },
+        highlight,
+    ): (Palette, Palette),
+    border_radius: f32,
+    border_width: f32,
+) -> iced::overlay::menu::Appearance {
+    iced::overlay::menu::Appearance {
+        text_color: text,
+        background: background.into(),
+        border_width,
+        border_radius,
+        border_color: border,
+        selected_text_color: highlight.background,
+        selected_background: highlight.foreground.into(),
+    }
+}
+
+impl iced::overlay::menu::StyleSheet for Theme {
+    type Style = theme::Menu;
+
+    fn appearance(&self, style: &Self::Style) -> iced::overlay::menu::Appearance {
+        match style {
+            theme::Menu::Custom(style_sheet) => style_sheet.appearance(self),
+            theme::Menu::Theme(var) => menu_appearance(
+                self.var_palette(*var),
+                self.border_radius(),
+                self.border_width(),
+            ),
+            theme::Menu::ContrastPalette(palette) => menu_appearance(
+                self.contrast_var_palette(*palette),
+                self.border_radius(),
+                self.border_width(),
+            ),
+        }
+    }
+}
+
+fn text_input_appearance(
+    Palette {
+        border, background, ..
+    }: Palette,
+    border_radius: f32,
+    border_width: f32,
+) -> iced::widget::text_input::Appearance {
+    iced::widget::text_input::Appearance {
+        background: background.into(),
+        border_radius,
+        border_width,
+        border_color: border,
+    }
+}
+
+impl iced::widget::text_input::StyleSheet for Theme {
+    type Style = theme::TextInput;
+
+    fn active(&self, style: &Self::Style) -> iced::widget::text_input::Appearance {
+        match style {
+            theme::TextInput::Custom(style_sheet) => style_sheet.active(self),
+            theme::TextInput::Theme(var) => text_input_appearance(
+                self.var_palette(*var).0,
+                self.border_radius(),
+                self.border_width(),
+            ),
+            theme::TextInput::ContrastPalette(palette) => text_input_appearance(
+                self.contrast_var_palette(*palette).0,
+                self.border_radius(),
+                self.border_width(),
+            ),
+        }
+    }
+
+    fn focused(&self, style: &Self::Style) -> iced::widget::text_input::Appearance {
+        match style {
+            theme::TextInput::Custom(style_sheet) => style_sheet.focused(self),
+            theme::TextInput::Theme(var) => text_input_appearance(
+                self.var_palette(*var).1,
+                self.border_radius(),
+                self.border_width(),
+            ),
+            theme::TextInput::ContrastPalette(palette) => text_input_appearance(
+                self.contrast_var_palette(*palette).1,
+                self.border_radius(),
+                self.border_width(),
+            ),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> iced::widget::text_input::Appearance {
+        match style {
+            theme::TextInput::Custom(style_sheet) => style_sheet.hovered(self),
+            _ => self.focused(style),
+        }
+    }
+
+    fn placeholder_color(&self, style: &Self::Style) -> Color {
+        match style {
+            theme::TextInput::Custom(style_sheet) => style_sheet.placeholder_color(self),
+            theme::TextInput::Theme(var) => self.var_palette(*var).0.text.mute(self.mute_amount()),
+            theme::TextInput::ContrastPalette(palette) => self
+                .contrast_var_palette(*palette)
+                .0
+                .text
+                .mute(self.mute_amount()),
+        }
+    }
+
+    fn value_color(&self, style: &Self::Style) -> Color {
+        match style {
+            theme::TextInput::Custom(style_sheet) => style_sheet.value_color(self),
+            theme::TextInput::Theme(var) => self.var_palette(*var).0.text,
+            theme::TextInput::ContrastPalette(palette) => {
+                self.contrast_var_palette(*palette).0.text
+            }
+        }
+    }
+
+    fn selection_color(&self, style: &Self::Style) -> Color {
+        match style {
+            theme::TextInput::Custom(style_sheet) => style_sheet.selection_color(self),
+            theme::TextInput::Theme(var) => self.var_palette(*var).1.foreground.mute(Some(0.5)),
+            theme::TextInput::ContrastPalette(palette) => self
+                .contrast_var_palette(*palette)
+                .1
+                .foreground
+                .mute(Some(0.5)),
+        }
+    }
+}
+
+fn checkbox_appearance(
+    Palette {
+        border,
+        background,
+        foreground,
+        text,
+    }: Palette,
+    border_radius: f32,
+    border_width: f32,
+    is_checked: bool,
+) -> iced::widget::checkbox::Appearance {
+    iced::widget::checkbox::Appearance {
+        background: if is_checked { foreground } else { background }.into(),
+        checkmark_color: background,
+        border_radius,
+        border_width,
+        border_color: border,
+        text_color: Some(text),
+    }
+}
+
+impl iced::widget::checkbox::StyleSheet for Theme {
+    type Style = theme::Checkbox;
+
+    fn active(&self, style: &Self::Style, is_checked: bool) -> iced::widget::checkbox::Appearance {
+        match style {
+            theme::Checkbox::Custom(style_sheet) => style_sheet.active(self, is_checked),
+            theme::Checkbox::Theme(var) => checkbox_appearance(
+                self.var_palette(*var).0,
+                self.border_radius(),
+                self.border_width(),
+                is_checked,
+            ),
+            theme::Checkbox::ContrastPalette(palette) => checkbox_appearance(
+                self.contrast_var_palette(*palette).0,
+                self.border_radius(),
+                self.border_width(),
+                is_checked,
+            ),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style, is_checked: bool) -> iced::widget::checkbox::Appearance {
+        match style {
+            theme::Checkbox::Custom(style_sheet) => style_sheet.hovered(self, is_checked),
+            theme::Checkbox::Theme(var) => checkbox_appearance(
+                self.var_palette(*var).1,
+                self.border_radius(),
+                self.border_width(),
+                is_checked,
+            ),
+            theme::Checkbox::ContrastPalette(palette) => checkbox_appearance(
+                self.contrast_var_palette(*palette).1,
+                self.border_radius(),
+                self.border_width(),
+                is_checked,
+            ),
+        }
+    }
+}
+
+fn slider_appearance(
+    Palette {
+        border,
+        background,
+        foreground,
+        ..
+    }: Palette,
+    mute: Option<f32>,
+    border_radius: f32,
+    border_width: f32,
+) -> iced::widget::slider::Appearance {
+    iced::widget::slider::Appearance {
+        rail_colors: (foreground, foreground.mute(mute)),
+        handle: iced::widget::slider::Handle {
+            shape: iced::widget::slider::HandleShape::Rectangle {
+                width: 8,
+                border_radius,
+            },
+            color: background,
+            border_width,
+            border_color: border,
+        },
+    }
+}
+
+impl iced::widget::slider::StyleSheet for Theme {
+    type Style = theme::Slider;
+
+    fn active(&self, style: &Self::Style) -> iced::widget::slider::Appearance {
+        match style {
+            theme::Slider::Custom(style_sheet) => style_sheet.active(self),
+            theme::Slider::Theme(var) => slider_appearance(
+                self.var_palette(*var).0,
+                self.mute_amount(),
+                self.border_radius(),
+                self.border_width(),
+            ),
+            theme::Slider::ContrastPalette(palette) => slider_appearance(
+                self.contrast_var_palette(*palette).0,
+                self.mute_amount(),
+                self.border_radius(),
+                self.border_width(),
+            ),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> iced::widget::slider::Appearance {
+        match style {
+            theme::Slider::Custom(style_sheet) => style_sheet.hovered(self),
+            theme::Slider::Theme(var) => slider_appearance(
+                self.var_palette(*var).1,
+                self.mute_amount(),
+                self.border_radius(),
+                self.border_width(),
+            ),
+            theme::Slider::ContrastPalette(palette) => slider_appearance(
+                self.contrast_var_palette(*palette).1,
+                self.mute_amount(),
+                self.border_radius(),
+                self.border_width(),
+            ),
+        }
+    }
+
+    fn dragging(&self, style: &Self::Style) -> iced::widget::slider::Appearance {
+        match style {
+            theme::Slider::Custom(style_sheet) => style_sheet.dragging(self),
+            theme::Slider::Theme(var) => slider_appearance(
+                self.var_palette(*var).0,
+                self.mute_amount(),
+                self.border_radius(),
+                self.border_width(),
+            ),
+            theme::Slider::ContrastPalette(palette) => slider_appearance(
+                self.contrast_var_palette(*palette).0,
+                self.mute_amount(),
+                self.border_radius(),
+                self.border_width(),
+            ),
+        }
+    }
+}
+
+fn progress_bar_appearance(
+    Palette {
+        background,
+        foreground,
+        ..
+    }: Palette,
+    border_radius: f32,
+) -> iced::widget::progress_bar::Appearance {
+    iced::widget::progress_bar::Appearance {
+        background: background.into(),
+        bar: foreground.into(),
+        border_radius,
+    }
+}
+
+impl iced::widget::progress_bar::StyleSheet for Theme {
+    type Style = theme::ProgressBar;
+
+    fn appearance(&self, style: &Self::Style) -> iced::widget::progress_bar::Appearance {
+        match style {
+            theme::ProgressBar::Custom(style_sheet) => style_sheet.appearance(self),
+            theme::ProgressBar::Theme(var) => {
+                progress_bar_appearance(self.var_palette(*var).0, self.border_radius())
+            }
+            theme::ProgressBar::ContrastPalette(palette) => {
+                progress_bar_appearance(self.contrast_var_palette(*palette).0, self.border_radius())
+            }
+        }
+    }
+}
+
+fn radio_appearance(
+    Palette {
+        border,
+        background,
+        foreground,
+        text,
+    }: Palette,
+    border_width: f32,
+    is_selected: bool,
+) -> iced::widget::radio::Appearance {
+    iced::widget::radio::Appearance {
+        background: background.into(),
+        dot_color: if is_selected { foreground } else { background },
+        border_width,
+        border_color: border,
+        text_color: Some(text),
+    }
+}
+
+impl iced::widget::radio::StyleSheet for Theme {
+    type Style = theme::Radio;
+
+    fn active(&self, style: &Self::Style, is_selected: bool) -> iced::widget::radio::Appearance {
+        match style {
+            theme::Radio::Custom(style_sheet) => style_sheet.active(self, is_selected),
+            theme::Radio::Theme(var) => {
+                radio_appearance(self.var_palette(*var).0, self.border_width(), is_selected)
+            }
+            theme::Radio::ContrastPalette(palette) => radio_appearance(
+                self.contrast_var_palette(*palette).0,
+                self.border_width(),
+                is_selected,
+            ),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style, is_selected: bool) -> iced::widget::radio::Appearance {
+        match style {
+            theme::Radio::Custom(style_sheet) => style_sheet.hovered(self, is_selected),
+            theme::Radio::Theme(var) => {
+                radio_appearance(self.var_palette(*var).1, self.border_width(), is_selected)
+            }
+            theme::Radio::ContrastPalette(palette) => radio_appearance(
+                self.contrast_var_palette(*palette).1,
+                self.border_width(),
+                is_selected,
+            ),
+        }
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn rule_appearance(
+    Palette { border, .. }: Palette,
+    border_width: f32,
+) -> iced::widget::rule::Appearance {
+    iced::widget::rule::Appearance {
+        color: border,
+        width: border_width.round().clamp(0.0, f32::from(u16::MAX)) as u16,
+        radius: 0.0,
+        fill_mode: iced::widget::rule::FillMode::Full,
+    }
+}
+
+impl iced::widget::rule::StyleSheet for Theme {
+    type Style = theme::Rule;
+
+    fn appearance(&self, style: &Self::Style) -> iced::widget::rule::Appearance {
+        match style {
+            theme::Rule::Custom(style_sheet) => style_sheet.appearance(self),
+            theme::Rule::Theme(var) => {
+                rule_appearance(self.var_palette(*var).0, self.border_width())
+            }
+            theme::Rule::ContrastPalette(palette) => {
+                rule_appearance(self.contrast_var_palette(*palette).0, self.border_width())
+            }
+        }
+    }
+}
+
+impl modal::StyleSheet for Theme {
+    type Style = theme::Modal;
+
+    fn appearance(&self, style: &Self::Style) -> modal::Appearance {
+        let palette = match style {
+            theme::Modal::Theme(var) => self.var_palette(*var).0,
+            theme::Modal::ContrastPalette(palette, var) => {
+                let (muted, palette) = self.contrast_var_palette(*palette);
+                match var {
+                    Var::Std => muted,
+                    Var::Alt => palette,
+                }
+            }
+            theme::Modal::Custom(style_sheet) => return style_sheet.appearance(self),
+        };
+        modal::Appearance {
+            backdrop: Color {
+                a: 0.6,
+                ..self.contrast_palette().dim
+            },
+            background: palette.background,
+            text_color: palette.text,
+            border_radius: self.border_radius(),
+            border_width: self.border_width(),
+            border_color: palette.border,
+        }
+    }
+}
@@ -0,0 +1,411 @@
+//! Module for [`Modal`] widget.
+
+use iced_native::{
+    event, keyboard, layout, mouse, overlay, renderer,
+    widget::{Operation, Tree},
+    Alignment, Clipboard, Color, Element, Event, Layout, Length, Padding, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+/// The appearance of a [`Modal`].
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// Color drawn over the base content, dimming it.
+    pub backdrop: Color,
+    /// Background color of the dialog.
+    pub background: Color,
+    /// Color of text in the dialog.
+    pub text_color: Color,
+    /// Border radius of the dialog.
+    pub border_radius: f32,
+    /// Border width of the dialog.
+    pub border_width: f32,
+    /// Border color of the dialog.
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a [`Modal`].
+pub trait StyleSheet {
+    /// Style to use.
+    type Style: Default;
+
+    /// Get the [`Appearance`] of a [`Modal`].
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
+
+/// A widget showing a dialog over its base content, which is dimmed and receives no events or
+/// operations while the dialog is shown. The dialog is closed by pressing escape or clicking
+/// outside of it, if the [`Modal`] has an [on close][Modal::on_close] message.
+pub struct Modal<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The base content followed by the dialog, if it is shown.
+    children: Vec<Element<'a, Message, Renderer>>,
+    on_close: Option<Box<dyn 'a + Fn() -> Message>>,
+    padding: Padding,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Modal<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Construct a new [`Modal`] around base content, showing dialog over it if it is some.
+    pub fn new(
+        base: impl Into<Element<'a, Message, Renderer>>,
+        dialog: Option<impl Into<Element<'a, Message, Renderer>>>,
+    ) -> Self {
+        Self {
+            children: [Some(base.into()), dialog.map(Into::into)]
+                .into_iter()
+                .flatten()
+                .collect(),
+            on_close: None,
+            padding: Padding::new(12),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the message factory used when the dialog is closed by escape or an outside click.
+    #[must_use]
+    pub fn on_close(self, on_close: impl 'a + Fn() -> Message) -> Self {
+        Self {
+            on_close: Some(Box::new(on_close)),
+            ..self
+        }
+    }
+
+    /// Sets the padding between the border of the dialog and its content.
+    #[must_use]
+    pub fn padding(self, padding: impl Into<Padding>) -> Self {
+        Self {
+            padding: padding.into(),
+            ..self
+        }
+    }
+
+    /// Sets the style of the [`Modal`].
+    #[must_use]
+    pub fn style(self, style: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
+        Self {
+            style: style.into(),
+            ..self
+        }
+    }
+
+    /// Check if the dialog is shown.
+    fn is_open(&self) -> bool {
+        self.children.len() > 1
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Modal<'_, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.children[0].as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.children[0].as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.children[0].as_widget().layout(renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.children[0].as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if !self.is_open() {
+            self.children[0].as_widget().operate(
+                &mut state.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if self.is_open() {
+            return event::Status::Ignored;
+        }
+        self.children[0].as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.is_open() {
+            return mouse::Interaction::Idle;
+        }
+        self.children[0].as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let Modal {
+            children,
+            on_close,
+            padding,
+            style,
+        } = self;
+        match (&mut children[..], &mut state.children[..]) {
+            ([_, dialog], [_, tree]) => Some(overlay::Element::new(
+                layout.position(),
+                Box::new(Overlay {
+                    content: dialog,
+                    tree,
+                    size: layout.bounds().size(),
+                    on_close: on_close.as_deref(),
+                    padding: *padding,
+                    style,
+                }),
+            )),
+            ([base, ..], [tree, ..]) => base.as_widget_mut().overlay(tree, layout, renderer),
+            _ => None,
+        }
+    }
+}
+
+/// Overlay of a [`Modal`] drawing the backdrop and dialog.
+struct Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: &'b mut Element<'a, Message, Renderer>,
+    tree: &'b mut Tree,
+    size: Size,
+    on_close: Option<&'b dyn Fn() -> Message>,
+    padding: Padding,
+    style: &'b <Renderer::Theme as StyleSheet>::Style,
+}
+
+/// Get the layouts of the dialog and its content from the layout of an [`Overlay`].
+fn dialog_layout(layout: Layout<'_>) -> Option<(Layout<'_>, Layout<'_>)> {
+    let dialog = layout.children().next()?;
+    let content = dialog.children().next()?;
+    Some((dialog, content))
+}
+
+impl<Message, Renderer> overlay::Overlay<Message, Renderer> for Overlay<'_, '_, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(&self, renderer: &Renderer, _bounds: Size, position: Point) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, self.size).pad(self.padding);
+        let mut content = self.content.as_widget().layout(renderer, &limits);
+        content.move_to(Point::new(
+            f32::from(self.padding.left),
+            f32::from(self.padding.top),
+        ));
+        let content_size = content.size();
+
+        let mut dialog = layout::Node::with_children(
+            Size::new(
+                content_size.width + f32::from(self.padding.horizontal()),
+                content_size.height + f32::from(self.padding.vertical()),
+            ),
+            vec![content],
+        );
+        dialog.align(Alignment::Center, Alignment::Center, self.size);
+
+        let mut node = layout::Node::with_children(self.size, vec![dialog]);
+        node.move_to(position);
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let appearance = theme.appearance(self.style);
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.backdrop,
+        );
+
+        let Some((dialog, content)) = dialog_layout(layout) else {
+            return;
+        };
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: dialog.bounds(),
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+        self.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: appearance.text_color,
+            },
+            content,
+            cursor_position,
+            &layout.bounds(),
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let Some((dialog, content)) = dialog_layout(layout) else {
+            return event::Status::Ignored;
+        };
+
+        let close = match &event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => true,
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                !dialog.bounds().contains(cursor_position)
+            }
+            _ => false,
+        };
+        if close {
+            if let Some(on_close) = self.on_close {
+                shell.publish(on_close());
+            }
+            return event::Status::Captured;
+        }
+
+        // Keyboard and mouse events never reach anything below the dialog.
+        let trapped = matches!(event, Event::Keyboard(_) | Event::Mouse(_));
+        let status = self.content.as_widget_mut().on_event(
+            self.tree,
+            event,
+            content,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+        if trapped {
+            event::Status::Captured
+        } else {
+            status
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        dialog_layout(layout).map_or(mouse::Interaction::Idle, |(_, content)| {
+            self.content.as_widget().mouse_interaction(
+                self.tree,
+                content,
+                cursor_position,
+                viewport,
+                renderer,
+            )
+        })
+    }
+}
+
+impl<'a, Message, Renderer> From<Modal<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(value: Modal<'a, Message, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
@@ -0,0 +1,195 @@
+//! Module for [`Reorder`] widget.
+
+use iced_native::{
+    event, layout, mouse, overlay, renderer,
+    widget::{tree, Operation, Tree},
+    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+/// A widget wrapping a row or column, which reports when one of its children is dragged onto
+/// another, by their indices. Events are still passed on to the children, so buttons only
+/// react when pressed and released on the same child.
+pub struct Reorder<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    count: usize,
+    on_drop: Box<dyn 'a + Fn(usize, usize) -> Message>,
+}
+
+impl<'a, Message, Renderer> Reorder<'a, Message, Renderer> {
+    /// Construct a new [`Reorder`] around content, where only the first count children may be
+    /// dragged or dropped onto, and on reorder receives the dragged and the dropped onto index.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        count: usize,
+        on_reorder: impl 'a + Fn(usize, usize) -> Message,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            count,
+            on_drop: Box::new(on_reorder),
+        }
+    }
+
+    /// Get the index of the child under the cursor, if it can be reordered.
+    fn index_at(&self, layout: Layout<'_>, cursor_position: Point) -> Option<usize> {
+        layout
+            .children()
+            .take(self.count)
+            .position(|child| child.bounds().contains(cursor_position))
+    }
+}
+
+/// State of a [`Reorder`], the index of the child being dragged.
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    dragging: Option<usize>,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Reorder<'_, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut state.children[0],
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let dragging = self.index_at(layout, cursor_position);
+                state.state.downcast_mut::<State>().dragging = dragging;
+                status
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let dragging = state.state.downcast_mut::<State>().dragging.take();
+                match (dragging, self.index_at(layout, cursor_position)) {
+                    (Some(from), Some(to)) if from != to => {
+                        shell.publish((self.on_drop)(from, to));
+                        event::Status::Captured
+                    }
+                    _ => status,
+                }
+            }
+            _ => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match state.state.downcast_ref::<State>().dragging {
+            Some(from) if self.index_at(layout, cursor_position) != Some(from) => {
+                mouse::Interaction::Grabbing
+            }
+            _ => self.content.as_widget().mouse_interaction(
+                &state.children[0],
+                layout,
+                cursor_position,
+                viewport,
+                renderer,
+            ),
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut state.children[0], layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Reorder<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn from(value: Reorder<'a, Message, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
@@ -0,0 +1,219 @@
+//! Module for [`Resize`] widget.
+
+use iced_native::{
+    event, layout, mouse, overlay, renderer,
+    widget::{tree, Operation, Tree},
+    Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+/// Width of the area at the right edge of the content which may be dragged.
+const HANDLE_WIDTH: f32 = 6.0;
+
+/// A widget wrapping content of a known width, which reports a new width while the right edge of
+/// the content is dragged. Other events are still passed on to the content.
+pub struct Resize<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    width: u16,
+    min_width: u16,
+    on_drag: Box<dyn 'a + Fn(u16) -> Message>,
+}
+
+impl<'a, Message, Renderer> Resize<'a, Message, Renderer> {
+    /// Construct a new [`Resize`] around content currently as wide as width, where on resize
+    /// receives the new width.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        width: u16,
+        on_resize: impl 'a + Fn(u16) -> Message,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            width,
+            min_width: 0,
+            on_drag: Box::new(on_resize),
+        }
+    }
+
+    /// Sets the smallest width which may be reported.
+    #[must_use]
+    pub fn min_width(self, min_width: u16) -> Self {
+        Self { min_width, ..self }
+    }
+
+    /// Check if the cursor is over the draggable right edge.
+    fn on_handle(layout: Layout<'_>, cursor_position: Point) -> bool {
+        let bounds = layout.bounds();
+        Rectangle {
+            x: bounds.x + bounds.width - HANDLE_WIDTH,
+            width: HANDLE_WIDTH,
+            ..bounds
+        }
+        .contains(cursor_position)
+    }
+
+    /// Get the width for the cursor at x, when the drag started at start with start width.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn dragged_width(&self, (start, start_width): (f32, u16), x: f32) -> u16 {
+        (f32::from(start_width) + x - start)
+            .round()
+            .clamp(f32::from(self.min_width), f32::from(u16::MAX)) as u16
+    }
+}
+
+/// State of a [`Resize`], the cursor x position and width when dragging started.
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    dragging: Option<(f32, u16)>,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Resize<'_, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let dragging = state.state.downcast_mut::<State>().dragging;
+        match (event, dragging) {
+            (Event::Mouse(mouse::Event::CursorMoved { position }), Some(drag)) => {
+                let width = self.dragged_width(drag, position.x);
+                if width != self.width {
+                    shell.publish((self.on_drag)(width));
+                }
+                event::Status::Captured
+            }
+            (Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)), Some(_)) => {
+                state.state.downcast_mut::<State>().dragging = None;
+                event::Status::Captured
+            }
+            (Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)), None)
+                if Self::on_handle(layout, cursor_position) =>
+            {
+                state.state.downcast_mut::<State>().dragging =
+                    Some((cursor_position.x, self.width));
+                event::Status::Captured
+            }
+            (event, _) => self.content.as_widget_mut().on_event(
+                &mut state.children[0],
+                event,
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            ),
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if state.state.downcast_ref::<State>().dragging.is_some()
+            || Self::on_handle(layout, cursor_position)
+        {
+            mouse::Interaction::ResizingHorizontally
+        } else {
+            self.content.as_widget().mouse_interaction(
+                &state.children[0],
+                layout,
+                cursor_position,
+                viewport,
+                renderer,
+            )
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut state.children[0], layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Resize<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn from(value: Resize<'a, Message, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
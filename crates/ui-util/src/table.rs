@@ -0,0 +1,268 @@
+//! Module for [`Table`] widget builder.
+
+use crate::{resize::Resize, virtual_list::VirtualList, IteratorWidgetExt, Renderer};
+use bookmark_util::AnyWithExt;
+use iced::{
+    widget::{button, container, scrollable, text, Column, Row},
+    Alignment, Element, Length,
+};
+use std::marker::PhantomData;
+use tap::Pipe;
+
+/// Smallest width a column may be resized to.
+const MIN_WIDTH: u16 = 32;
+
+/// Current sorting of a [`Table`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sort {
+    /// Index of the column sorted by.
+    pub column: usize,
+    /// If the sort is in descending order.
+    pub descending: bool,
+}
+
+/// Function building the cell of an item.
+type Cell<'a, Item, Message> = Box<dyn 'a + Fn(&Item) -> Element<'a, Message, Renderer>>;
+
+/// A column of a [`Table`].
+pub struct TableColumn<'a, Item, Message> {
+    header: String,
+    width: u16,
+    cell: Cell<'a, Item, Message>,
+    sortable: bool,
+}
+
+impl<'a, Item, Message> TableColumn<'a, Item, Message> {
+    /// Construct a new [`TableColumn`] with a header and a function building the cell of an item.
+    pub fn new(
+        header: impl Into<String>,
+        cell: impl 'a + Fn(&Item) -> Element<'a, Message, Renderer>,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            width: 150,
+            cell: Box::new(cell),
+            sortable: false,
+        }
+    }
+
+    /// Sets the width of the column.
+    #[must_use]
+    pub fn width(self, width: u16) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Make the column sortable, allowing its header to be clicked to change the sorting.
+    #[must_use]
+    pub fn sortable(self) -> Self {
+        Self {
+            sortable: true,
+            ..self
+        }
+    }
+}
+
+/// Events emitted by the header of a [`Table`].
+#[derive(Clone, Copy, Debug)]
+enum Event {
+    Sort(Sort),
+    Resize(usize, u16),
+}
+
+/// A widget showing items as rows with sortable, resizable columns. Items are shown in the order
+/// they are passed in, so they should already be sorted by the current [`Sort`].
+pub struct Table<'a, 'b, Item, Message> {
+    _lifetime: PhantomData<&'a Message>,
+    items: &'b [Item],
+    columns: Vec<TableColumn<'a, Item, Message>>,
+    sort: Option<Sort>,
+    on_sort: Option<Box<dyn 'a + Fn(Sort) -> Message>>,
+    on_resize: Option<Box<dyn 'a + Fn(usize, u16) -> Message>>,
+    offset: f32,
+    on_scroll: Box<dyn 'a + Fn(f32) -> Message>,
+    row_height: u16,
+    viewport_height: u32,
+    id: Option<scrollable::Id>,
+}
+
+impl<'a, 'b, Item, Message> Table<'a, 'b, Item, Message> {
+    /// Construct a new [`Table`] over items, scrolled to a relative offset.
+    pub fn new(items: &'b [Item], offset: f32, on_scroll: impl 'a + Fn(f32) -> Message) -> Self {
+        Self {
+            _lifetime: PhantomData,
+            items,
+            columns: Vec::new(),
+            sort: None,
+            on_sort: None,
+            on_resize: None,
+            offset,
+            on_scroll: Box::new(on_scroll),
+            row_height: 24,
+            viewport_height: 768,
+            id: None,
+        }
+    }
+
+    /// Add a column to the table.
+    #[must_use]
+    pub fn push(mut self, column: TableColumn<'a, Item, Message>) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Sets the current sorting of the table, shown in the header of the sorted column.
+    #[must_use]
+    pub fn sort(self, sort: Option<Sort>) -> Self {
+        Self { sort, ..self }
+    }
+
+    /// Sets the message produced when a header is clicked to change sorting.
+    #[must_use]
+    pub fn on_sort(self, on_sort: impl 'a + Fn(Sort) -> Message) -> Self {
+        Self {
+            on_sort: Some(Box::new(on_sort)),
+            ..self
+        }
+    }
+
+    /// Sets the message produced when a column is resized, given column index and new width.
+    #[must_use]
+    pub fn on_resize(self, on_resize: impl 'a + Fn(usize, u16) -> Message) -> Self {
+        Self {
+            on_resize: Some(Box::new(on_resize)),
+            ..self
+        }
+    }
+
+    /// Sets the height of every row.
+    #[must_use]
+    pub fn row_height(self, row_height: u16) -> Self {
+        Self { row_height, ..self }
+    }
+
+    /// Sets the height of the visible area, used to determine which rows are built.
+    #[must_use]
+    pub fn viewport_height(self, viewport_height: u32) -> Self {
+        Self {
+            viewport_height,
+            ..self
+        }
+    }
+
+    /// Sets the id of the [`Scrollable`][iced::widget::Scrollable] holding the rows.
+    #[must_use]
+    pub fn id(self, id: scrollable::Id) -> Self {
+        Self {
+            id: Some(id),
+            ..self
+        }
+    }
+
+    fn header(&self) -> Row<'a, Event, Renderer> {
+        let sortable = self.on_sort.is_some();
+        let resizable = self.on_resize.is_some();
+        self.columns
+            .iter()
+            .enumerate()
+            .collect_row(|(index, column)| {
+                let indicator = match self.sort {
+                    Some(Sort { column, descending }) if column == index => {
+                        if descending {
+                            " v"
+                        } else {
+                            " ^"
+                        }
+                    }
+                    _ => "",
+                };
+                let sort = Sort {
+                    column: index,
+                    descending: self.sort
+                        == Some(Sort {
+                            column: index,
+                            descending: false,
+                        }),
+                };
+                let header = text(format!("{}{indicator}", column.header))
+                    .width(Length::Fill)
+                    .pipe(button)
+                    .width(Length::Units(column.width))
+                    .padding(3)
+                    .pipe(|btn| {
+                        if sortable && column.sortable {
+                            btn.on_press(Event::Sort(sort))
+                        } else {
+                            btn
+                        }
+                    });
+                if resizable {
+                    Resize::new(header, column.width, move |width| {
+                        Event::Resize(index, width)
+                    })
+                    .min_width(MIN_WIDTH)
+                    .pipe(Element::from)
+                } else {
+                    header.into()
+                }
+            })
+            .align_items(Alignment::Center)
+    }
+}
+
+impl<'a, 'b, Item, Message> From<Table<'a, 'b, Item, Message>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+{
+    fn from(value: Table<'a, 'b, Item, Message>) -> Self {
+        let header = value.header();
+        let Table {
+            items,
+            columns,
+            on_sort,
+            on_resize,
+            offset,
+            on_scroll,
+            row_height,
+            viewport_height,
+            id,
+            ..
+        } = value;
+
+        let body = VirtualList::new(
+            items,
+            offset,
+            |item| {
+                columns
+                    .iter()
+                    .collect_row(|column| {
+                        (column.cell)(item)
+                            .pipe(container)
+                            .width(Length::Units(column.width))
+                    })
+                    .align_items(Alignment::Center)
+                    .pipe(Element::from)
+            },
+            on_scroll,
+        )
+        .row_height(row_height)
+        .viewport_height(viewport_height)
+        .with(id, VirtualList::id)
+        .pipe(Element::from);
+
+        Column::new()
+            .push(
+                Element::from(header).map(move |event| match (event, &on_sort, &on_resize) {
+                    (Event::Sort(sort), Some(on_sort), _) => on_sort(sort),
+                    (Event::Resize(column, width), _, Some(on_resize)) => on_resize(column, width),
+                    _ => unreachable!(concat!(
+                        "header events are only emitted when the table ",
+                        "has a callback for them",
+                    )),
+                }),
+            )
+            .push(body)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
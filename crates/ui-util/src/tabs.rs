@@ -1,108 +1,360 @@
 //! Module for [Tabs] widget builder.
 
-use crate::IteratorWidgetExt;
+use crate::{reorder::Reorder, IteratorWidgetExt, Renderer};
+use bookmark_util::AnyWithExt;
 use iced::{
     alignment::Horizontal,
-    widget::{button, container, text, Column},
+    widget::{button, container, pick_list, scrollable, text, Column, Row},
     Element, Length,
 };
-use std::marker::PhantomData;
+use std::{fmt::Display, marker::PhantomData, ops::Range};
 use tap::Pipe;
 
+/// Width of the dropdown holding tabs that do not fit.
+const OVERFLOW_WIDTH: u16 = 100;
+
+/// Events emitted by the tab bar of [Tabs].
+#[derive(Clone, Copy, Debug)]
+enum Event {
+    Select(usize),
+    Close(usize),
+    Reorder(usize, usize),
+    New,
+}
+
+/// A tab that does not fit, shown in the overflow dropdown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Hidden {
+    index: usize,
+    label: String,
+}
+
+impl Display for Hidden {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
 /// A Widget representing a tab view.
 pub struct Tabs<'a, 'b, State, OnChoice, Content, Message, Widget> {
     _lifetime: PhantomData<&'a (Message, Widget)>,
-    tabs: &'b [State],
+    states: &'b [State],
     current: usize,
     on_choice: OnChoice,
     content: Content,
     horizontal: bool,
+    tab_width: u16,
+    available_width: Option<u32>,
+    on_close: Option<Box<dyn 'a + Fn(usize) -> Message>>,
+    on_reorder: Option<Box<dyn 'a + Fn(usize, usize) -> Message>>,
+    on_new: Option<Box<dyn 'a + Fn() -> Message>>,
+    placeholder: Option<Element<'a, Message, Renderer>>,
 }
 
 impl<'a, 'b, Message, State, OnChoice, Content, Widget>
     Tabs<'a, 'b, State, OnChoice, Content, Message, Widget>
 {
-    /// Construct a new [Tabs] with passed arguments and functions to determine state.
-    ///
-    /// # Panics
-    /// If current is not an index of tabs.
+    /// Construct a new [Tabs] with passed arguments and functions to determine state. If
+    /// current is not an index of tabs the last tab is shown, without tabs the
+    /// [placeholder][Tabs::placeholder] is.
     pub fn new(tabs: &'b [State], current: usize, on_choice: OnChoice, content: Content) -> Self
     where
         State: ToString,
         OnChoice: 'a + Clone + Fn(usize) -> Message,
         Content: FnMut(&State) -> Widget,
         Message: 'a,
-        Widget: Into<Element<'a, Message>>,
+        Widget: Into<Element<'a, Message, Renderer>>,
     {
-        assert!((0..tabs.len()).contains(&current));
         Self {
-            _lifetime: PhantomData::default(),
-            tabs,
+            _lifetime: PhantomData,
+            states: tabs,
             current,
             on_choice,
             content,
             horizontal: false,
+            tab_width: 150,
+            available_width: None,
+            on_close: None,
+            on_reorder: None,
+            on_new: None,
+            placeholder: None,
+        }
+    }
+
+    /// Sets if tabs are stacked in a sidebar left of the content, instead of in a row above it.
+    #[must_use]
+    pub fn horizontal(self, horizontal: bool) -> Self {
+        Self { horizontal, ..self }
+    }
+
+    /// Sets the width of tabs, the maximum width when in a row and the sidebar width when
+    /// [horizontal][Tabs::horizontal].
+    #[must_use]
+    pub fn tab_width(self, tab_width: u16) -> Self {
+        Self { tab_width, ..self }
+    }
+
+    /// Sets the width available to the row of tabs. Tabs that do not fit are moved to a
+    /// dropdown, keeping the current tab in view.
+    #[must_use]
+    pub fn available_width(self, available_width: u32) -> Self {
+        Self {
+            available_width: Some(available_width),
+            ..self
+        }
+    }
+
+    /// Show a close button on every tab, producing a message with the index of the tab.
+    #[must_use]
+    pub fn on_close(self, on_close: impl 'a + Fn(usize) -> Message) -> Self {
+        Self {
+            on_close: Some(Box::new(on_close)),
+            ..self
+        }
+    }
+
+    /// Allow tabs to be dragged onto each other, producing a message with the index of the
+    /// dragged tab and the index it is moved to.
+    #[must_use]
+    pub fn on_reorder(self, on_reorder: impl 'a + Fn(usize, usize) -> Message) -> Self {
+        Self {
+            on_reorder: Some(Box::new(on_reorder)),
+            ..self
+        }
+    }
+
+    /// Show a button after the tabs producing a message to open a new tab.
+    #[must_use]
+    pub fn on_new(self, on_new: impl 'a + Fn() -> Message) -> Self {
+        Self {
+            on_new: Some(Box::new(on_new)),
+            ..self
+        }
+    }
+
+    /// Sets the element shown in place of content when there are no tabs.
+    #[must_use]
+    pub fn placeholder(self, placeholder: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Self {
+            placeholder: Some(placeholder.into()),
+            ..self
+        }
+    }
+
+    /// Get the range of tabs shown in the row, the rest are in the overflow dropdown.
+    fn visible(&self) -> Range<usize> {
+        let len = self.states.len();
+        let Some(available_width) = self.available_width else {
+            return 0..len;
+        };
+        let tab_width = u32::from(self.tab_width).max(1);
+        let fits = |width: u32| usize::try_from(width / tab_width).unwrap_or(usize::MAX);
+        if self.horizontal || fits(available_width) >= len {
+            return 0..len;
+        }
+        let fits = fits(available_width.saturating_sub(u32::from(OVERFLOW_WIDTH))).max(1);
+        let start = (self.current + 1).saturating_sub(fits);
+        start..(start + fits).min(len)
+    }
+
+    /// Build the tab at an index, with a close button if tabs are closable.
+    fn tab(&self, index: usize, alignment: Horizontal) -> Row<'a, Event, Renderer>
+    where
+        State: ToString,
+    {
+        Row::new()
+            .push(
+                self.states[index]
+                    .to_string()
+                    .pipe(text)
+                    .horizontal_alignment(alignment)
+                    .width(Length::Fill)
+                    .pipe(button)
+                    .with(
+                        (index != self.current).then_some(Event::Select(index)),
+                        button::Button::on_press,
+                    )
+                    .style(style::Tab::build())
+                    .width(Length::Fill),
+            )
+            .with(
+                self.on_close.is_some().then_some(Event::Close(index)),
+                |row, close| {
+                    row.push(
+                        text("x")
+                            .pipe(button)
+                            .on_press(close)
+                            .style(style::Tab::build()),
+                    )
+                },
+            )
+            .width(Length::Fill)
+    }
+
+    /// Build the bar of tabs in visible range, with a dropdown for the rest when not
+    /// horizontal.
+    fn bar(&self) -> Element<'a, Event, Renderer>
+    where
+        State: ToString,
+    {
+        let Tabs {
+            states,
+            horizontal,
+            tab_width,
+            ..
+        } = *self;
+        let visible = self.visible();
+        let reorderable = self.on_reorder.is_some();
+        let alignment = if horizontal {
+            Horizontal::Left
+        } else {
+            Horizontal::Center
+        };
+        let tab = |index| self.tab(index, alignment);
+        let new_tab = self.on_new.as_ref().map(|_| {
+            text("+")
+                .horizontal_alignment(Horizontal::Center)
+                .pipe(button)
+                .on_press(Event::New)
+                .style(style::Tab::build())
+        });
+        let reorder = |bar: Element<'a, Event, Renderer>| {
+            if reorderable {
+                let start = visible.start;
+                Reorder::new(bar, visible.len(), move |from, to| {
+                    Event::Reorder(start + from, start + to)
+                })
+                .into()
+            } else {
+                bar
+            }
+        };
+
+        if horizontal {
+            visible
+                .clone()
+                .collect_column(tab)
+                .with(new_tab, Column::push)
+                .width(Length::Fill)
+                .pipe(Element::from)
+                .pipe(reorder)
+                .pipe(scrollable)
+                .height(Length::Fill)
+                .pipe(container)
+                .width(Length::Units(tab_width))
+                .height(Length::Fill)
+                .pipe(Element::from)
+        } else {
+            let hidden = (0..states.len())
+                .filter(|index| !visible.contains(index))
+                .map(|index| Hidden {
+                    index,
+                    label: states[index].to_string(),
+                })
+                .collect::<Vec<_>>();
+            visible
+                .clone()
+                .collect_row(|index| {
+                    tab(index)
+                        .pipe(container)
+                        .width(Length::Fill)
+                        .max_width(u32::from(tab_width))
+                })
+                .pipe(|row| {
+                    if hidden.is_empty() {
+                        row
+                    } else {
+                        row.push(
+                            pick_list(hidden, None, |hidden| Event::Select(hidden.index))
+                                .placeholder("more")
+                                .width(Length::Units(OVERFLOW_WIDTH)),
+                        )
+                    }
+                })
+                .with(new_tab, Row::push)
+                .pipe(Element::from)
+                .pipe(reorder)
         }
     }
 }
 
 impl<'a, Message, State, OnChoice, Content, Widget>
-    From<Tabs<'a, '_, State, OnChoice, Content, Message, Widget>> for Element<'a, Message>
+    From<Tabs<'a, '_, State, OnChoice, Content, Message, Widget>> for Element<'a, Message, Renderer>
 where
     State: ToString,
     Message: 'a,
-    Widget: Into<Element<'a, Message>>,
+    Widget: Into<Element<'a, Message, Renderer>>,
     OnChoice: 'a + Clone + Fn(usize) -> Message,
     Content: FnMut(&State) -> Widget,
 {
     fn from(mut value: Tabs<'a, '_, State, OnChoice, Content, Message, Widget>) -> Self {
-        if value.horizontal {
-            todo!()
+        value.current = value.current.min(value.states.len().saturating_sub(1));
+        let bar = value.bar();
+        let content = match value.states.get(value.current) {
+            Some(state) => (value.content)(state).into(),
+            None => value.placeholder.take().unwrap_or_else(|| {
+                text("no tabs")
+                    .pipe(container)
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .center_x()
+                    .center_y()
+                    .into()
+            }),
+        };
+        let Tabs {
+            on_choice,
+            horizontal,
+            on_close,
+            on_reorder,
+            on_new,
+            ..
+        } = value;
+
+        let bar = bar.map(
+            move |event| match (event, &on_close, &on_reorder, &on_new) {
+                (Event::Select(index), ..) => on_choice(index),
+                (Event::Close(index), Some(on_close), ..) => on_close(index),
+                (Event::Reorder(from, to), _, Some(on_reorder), _) => on_reorder(from, to),
+                (Event::New, .., Some(on_new)) => on_new(),
+                _ => unreachable!(concat!(
+                    "tab bar events are only emitted when the tabs ",
+                    "have a callback for them",
+                )),
+            },
+        );
+
+        if horizontal {
+            Row::new()
+                .push(bar)
+                .push(content)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .pipe(container)
         } else {
             Column::new()
-                .push(value.tabs.iter().enumerate().collect_row(|(index, tab)| {
-                    tab.to_string()
-                        .pipe(text)
-                        .horizontal_alignment(Horizontal::Center)
-                        .width(Length::Fill)
-                        .pipe(button)
-                        .pipe(|btn| {
-                            if index == value.current {
-                                btn
-                            } else {
-                                btn.on_press(index)
-                            }
-                        })
-                        .style(style::Tab::build())
-                        .width(Length::Fill)
-                        .pipe(container)
-                        .width(Length::Fill)
-                        .max_width(150)
-                        .pipe(Element::from)
-                        .map(value.on_choice.clone())
-                }))
-                .push((value.content)(&value.tabs[value.current]))
+                .push(bar)
+                .push(content)
                 .width(Length::Fill)
                 .height(Length::Fill)
                 .pipe(container)
-                .style(style::Content::build())
-                .into()
         }
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .style(Some(style::Content::build()))
+        .into()
     }
 }
 
 mod style {
-    use iced::{
-        theme,
-        widget::{button, container},
-        Theme,
-    };
+    use crate::{color::ColorManipExt, theme, Theme};
+    use iced::widget::{button, container};
 
     pub struct Tab;
 
     impl Tab {
         pub fn build() -> theme::Button {
-            theme::Button::Custom(Box::new(Self))
+            theme::Button::Custom((&Self as &dyn button::StyleSheet<Style = Theme>).into())
         }
     }
 
@@ -110,37 +362,37 @@ mod style {
         type Style = Theme;
 
         fn active(&self, style: &Self::Style) -> button::Appearance {
-            let palette = style.extended_palette();
+            let palette = style.theme_palette().mute;
 
             button::Appearance {
-                background: Some(palette.background.strong.color.into()),
+                background: Some(palette.background.lerp(palette.foreground, 0.2).into()),
                 border_radius: 0.0,
                 border_width: 0.0,
-                text_color: palette.background.weak.text,
+                text_color: palette.text,
                 ..Default::default()
             }
         }
 
         fn disabled(&self, style: &Self::Style) -> button::Appearance {
-            let palette = style.extended_palette();
+            let palette = style.theme_palette().mute;
 
             button::Appearance {
-                background: Some(palette.background.base.color.into()),
+                background: Some(palette.background.into()),
                 border_radius: 0.0,
                 border_width: 0.0,
-                text_color: palette.background.strong.text,
+                text_color: palette.text,
                 ..Default::default()
             }
         }
 
         fn hovered(&self, style: &Self::Style) -> button::Appearance {
-            let palette = style.extended_palette();
+            let palette = style.theme_palette().mute_highlight;
 
             button::Appearance {
-                background: Some(palette.background.weak.color.into()),
+                background: Some(palette.background.lerp(palette.foreground, 0.1).into()),
                 border_radius: 0.0,
                 border_width: 0.0,
-                text_color: palette.background.strong.text,
+                text_color: palette.text,
                 ..Default::default()
             }
         }
@@ -154,7 +406,7 @@ mod style {
 
     impl Content {
         pub fn build() -> theme::Container {
-            theme::Container::Custom(Box::new(Self))
+            theme::Container::Custom((&Self as &dyn container::StyleSheet<Style = Theme>).into())
         }
     }
 
@@ -162,11 +414,11 @@ mod style {
         type Style = Theme;
 
         fn appearance(&self, style: &Self::Style) -> container::Appearance {
-            let palette = style.extended_palette();
+            let palette = style.theme_palette().mute;
 
             container::Appearance {
-                text_color: Some(palette.background.base.text),
-                background: Some(palette.background.base.color.into()),
+                text_color: Some(palette.text),
+                background: Some(palette.background.into()),
                 border_radius: 0.0,
                 border_width: 0.0,
                 ..Default::default()
@@ -1,18 +1,35 @@
 //! Module for [`TextButton`] widget builder.
 
+use crate::{
+    icon::Icon,
+    theme::{self, Var},
+    Renderer,
+};
 use bookmark_util::AnyWithExt;
 use derivative::Derivative;
 use iced::{
-    theme,
-    widget::{button, text, Button},
-    Element, Length, Padding,
+    widget::{button, svg, text, tooltip, Button, Row, Tooltip},
+    Alignment, Command, Element, Length, Padding,
 };
-use std::marker::PhantomData;
+use std::{marker::PhantomData, time::Duration};
 use tap::Pipe;
+use tokio::time;
+
+/// Time an armed [`TextButton`] waits for the confirming press, see [`disarm_after`].
+pub const CONFIRM_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Get a command producing message once [`CONFIRM_TIMEOUT`] has passed, used to disarm a
+/// [`TextButton`] set to [confirm][TextButton::confirm].
+pub fn disarm_after<Message>(message: Message) -> Command<Message>
+where
+    Message: 'static + Send,
+{
+    Command::perform(time::sleep(CONFIRM_TIMEOUT), move |()| message)
+}
 
 /// A widget for buttons with text content and messages not needing clone implementations.
-#[derive(Debug, Derivative)]
-#[derivative(Default(bound = ""))]
+#[derive(Derivative)]
+#[derivative(Debug, Default(bound = ""))]
 pub struct TextButton<'a, Message, OnPress = ()> {
     _lifetime: PhantomData<&'a Message>,
     content: String,
@@ -21,22 +38,44 @@ pub struct TextButton<'a, Message, OnPress = ()> {
     height: Option<Length>,
     padding: Option<Padding>,
     style: Style,
+    icon: Option<Icon>,
+    trailing_icon: Option<Icon>,
+    tooltip: Option<String>,
+    shortcut: Option<String>,
+    #[derivative(Debug = "ignore")]
+    confirm: Option<(bool, Box<dyn 'a + Fn() -> Message>)>,
+    confirm_content: Option<String>,
 }
 
-/// Style used by [`TextButton`].
+/// Size of icons in a [`TextButton`].
+const ICON_SIZE: u16 = 16;
+
+/// Style used by [`TextButton`], resolved from the [`ThemePalette`][crate::color::ThemePalette]
+/// of the current theme.
 #[derive(Clone, Copy, Debug, Default)]
 pub enum Style {
-    /// The primary look for buttons.
+    /// The primary look for buttons, filled using the mute palette.
     #[default]
     Primary,
-    /// A Secondary look for buttons.
+    /// A Secondary look for buttons, outlined using the mute palette.
     Secondary,
-    /// A look for buttons with "positive" actions.
+    /// A look for buttons with "positive" actions, using the success palette.
     Positive,
-    /// A look for buttons with "negative" actions.
+    /// A look for buttons with "negative" actions, using the danger palette.
     Destructive,
 }
 
+impl From<Style> for theme::Button {
+    fn from(value: Style) -> Self {
+        match value {
+            Style::Primary => theme::Button::Theme(Var::Std),
+            Style::Secondary => theme::Button::Theme(Var::Alt),
+            Style::Positive => theme::Button::Success,
+            Style::Destructive => theme::Button::Danger,
+        }
+    }
+}
+
 impl<Message> TextButton<'_, Message, ()> {
     /// Create a new [`TextButton`] with given content and no action on press.
     #[must_use]
@@ -92,111 +131,148 @@ impl<'a, Message, OnPress> TextButton<'a, Message, OnPress> {
         Self { style, ..self }
     }
 
-    fn button(
-        content: String,
-        width: Option<Length>,
-        height: Option<Length>,
-        padding: Option<Padding>,
-        style: Style,
-    ) -> Button<'a, ()> {
-        button(text(content))
+    /// Sets an icon shown before the content.
+    #[must_use]
+    pub fn icon(self, icon: Icon) -> Self {
+        Self {
+            icon: Some(icon),
+            ..self
+        }
+    }
+
+    /// Sets an icon shown after the content.
+    #[must_use]
+    pub fn trailing_icon(self, trailing_icon: Icon) -> Self {
+        Self {
+            trailing_icon: Some(trailing_icon),
+            ..self
+        }
+    }
+
+    /// Sets a tooltip shown when the button is hovered.
+    #[must_use]
+    pub fn tooltip(self, tooltip: &impl ToString) -> Self {
+        Self {
+            tooltip: Some(tooltip.to_string()),
+            ..self
+        }
+    }
+
+    /// Sets a keyboard shortcut hint shown after the content, such as `Ctrl+S`.
+    #[must_use]
+    pub fn shortcut(self, shortcut: &impl ToString) -> Self {
+        Self {
+            shortcut: Some(shortcut.to_string()),
+            ..self
+        }
+    }
+
+    /// Require a second press before on press fires. While not armed a press produces the
+    /// message of on arm, which should arm the button, and while armed the button shows its
+    /// [confirm content][TextButton::confirm_content] in the [`Style::Destructive`] style and a
+    /// press fires on press. Use [`disarm_after`] to disarm it again after a timeout.
+    #[must_use]
+    pub fn confirm(self, armed: bool, on_arm: impl 'a + Fn() -> Message) -> Self {
+        Self {
+            confirm: Some((armed, Box::new(on_arm))),
+            ..self
+        }
+    }
+
+    /// Sets the content shown while armed, `"confirm <content>"` by default.
+    #[must_use]
+    pub fn confirm_content(self, confirm_content: &impl ToString) -> Self {
+        Self {
+            confirm_content: Some(confirm_content.to_string()),
+            ..self
+        }
+    }
+
+    /// Build the button, pressable if there is an on press factory, which is returned with it.
+    fn build(self, armed: bool) -> (Element<'a, (), Renderer>, Option<OnPress>) {
+        let TextButton {
+            content,
+            on_press,
+            width,
+            height,
+            padding,
+            style,
+            icon,
+            trailing_icon,
+            tooltip,
+            shortcut,
+            confirm_content,
+            ..
+        } = self;
+        let (content, style) = if armed {
+            (
+                confirm_content.unwrap_or_else(|| format!("confirm {content}")),
+                Style::Destructive,
+            )
+        } else {
+            (content, style)
+        };
+        let icon_svg = |icon: Icon| {
+            svg(icon.handle())
+                .width(Length::Units(ICON_SIZE))
+                .height(Length::Units(ICON_SIZE))
+                .style(theme::Svg::Button(style.into()))
+        };
+
+        let button = Row::new()
+            .with(icon, |row, icon| row.push(icon_svg(icon)))
+            .push(text(content))
+            .with(shortcut, |row, shortcut| row.push(text(shortcut).size(14)))
+            .with(trailing_icon, |row, icon| row.push(icon_svg(icon)))
+            .spacing(6)
+            .align_items(Alignment::Center)
+            .pipe(button)
             .with(width, Button::width)
             .with(height, Button::height)
             .padding(padding.unwrap_or(Padding::from(3)))
-            .style(theme::Button::Custom(Box::new(style)))
+            .style(theme::Button::from(style))
+            .with(on_press.as_ref().map(|_| ()), Button::on_press);
+
+        let element = match tooltip {
+            Some(tooltip) => Tooltip::new(button, tooltip, tooltip::Position::Bottom)
+                .gap(3)
+                .padding(3)
+                .style(theme::Container::Theme(Var::Alt))
+                .into(),
+            None => button.into(),
+        };
+        (element, on_press)
     }
 }
 
-impl<'a, Message, OnPress> From<TextButton<'a, Message, OnPress>> for Element<'a, Message>
+impl<'a, Message, OnPress> From<TextButton<'a, Message, OnPress>> for Element<'a, Message, Renderer>
 where
     OnPress: 'static + Fn() -> Message,
 {
-    fn from(value: TextButton<'a, Message, OnPress>) -> Self {
-        let TextButton {
-            content, on_press: Some(on_press), width, height,padding,style,..
-        } = value else {
+    fn from(mut value: TextButton<'a, Message, OnPress>) -> Self {
+        let confirm = value.confirm.take();
+        let armed = matches!(confirm, Some((true, _)));
+        let (button, Some(on_press)) = value.build(armed) else {
             panic!(concat!(
                 "when a bookmark_ui_util::button::Button has a <Fn() -> Message> ",
                 "OnPress generic parameter the on_press field should always have ",
                 "a value specified",
             ));
         };
-        TextButton::<Message>::button(content, width, height, padding, style)
-            .on_press(())
-            .pipe(Element::from)
-            .map(move |_: ()| on_press())
+        match confirm {
+            Some((false, on_arm)) => button.map(move |()| on_arm()),
+            _ => button.map(move |()| on_press()),
+        }
     }
 }
 
-impl<'a, Message> From<TextButton<'a, Message>> for Element<'a, Message> {
+impl<'a, Message> From<TextButton<'a, Message>> for Element<'a, Message, Renderer> {
     fn from(value: TextButton<'a, Message>) -> Self {
-        let TextButton {
-            content,
-            width,
-            height,
-            padding,
-            style,
-            ..
-        } = value;
-        TextButton::<Message>::button(content, width, height, padding, style)
-            .pipe(Element::from)
-            .map(|_: ()| {
-                unimplemented!(concat!(
-                    "this message should never be fired since ",
-                    "on_press has not been called for the button",
-                ))
-            })
+        value.build(false).0.map(|()| {
+            unimplemented!(concat!(
+                "this message should never be fired since ",
+                "on_press has not been called for the button",
+            ))
+        })
     }
 }
-
-macro_rules! button_appearance {
-    ($kind:ident, $strength:ident, $palette:expr) => {{
-        button::Appearance {
-            background: Some($palette.$kind.$strength.color.into()),
-            text_color: $palette.primary.strong.text,
-            ..Default::default()
-        }
-    }};
-}
-
-macro_rules! button_style_impl {
-    ($ty:ty, {$($arm:pat => $kind:ident),+ $(,)?}) => {
-        impl button::StyleSheet for $ty {
-            type Style = iced::Theme;
-
-            fn active(&self, style: &Self::Style) -> button::Appearance {
-                let palette = style.extended_palette();
-                match self {
-                    $(
-                        $arm => button_appearance!($kind, strong, palette),
-                    )*
-                }
-            }
-
-            fn pressed(&self, style: &Self::Style) -> button::Appearance {
-                let palette = style.extended_palette();
-                match self {
-                    $(
-                        $arm => button_appearance!($kind, weak, palette),
-                    )*
-                }
-            }
-
-            fn hovered(&self, style: &Self::Style) -> button::Appearance {
-                let palette = style.extended_palette();
-                match self {
-                    $(
-                        $arm => button_appearance!($kind, base, palette),
-                    )*
-                }
-            }
-        }
-    };
-}
-
-button_style_impl!(Style, {
-    Style::Primary => primary,
-    Style::Secondary => secondary,
-    Style::Positive => success,
-    Style::Destructive => danger,
-});
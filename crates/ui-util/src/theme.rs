@@ -38,6 +38,10 @@ pub enum Container {
     /// Use a palette based on contrast swapping what is foreground and background based on
     /// theme
     ContrastPalette(ContrastPalette, Var),
+    /// Use the success palette of the current theme, with a border.
+    Success,
+    /// Use the danger palette of the current theme, with a border.
+    Danger,
     /// Implement the style yourself and pass it.
     Custom(Somewhere<dyn iced::widget::container::StyleSheet<Style = Theme>>),
 }
@@ -48,6 +52,8 @@ pub enum Text {
     /// Use the default style of the current theme.
     #[default]
     Theme,
+    /// Use the default style of the current theme, but dimmer, for secondary information.
+    Dim,
     /// Use a palette based on contrast swapping what is foreground and background based on
     /// theme
     ContrastPalette(ContrastPalette),
@@ -73,6 +79,177 @@ pub enum Button {
     /// Use the default style of the current theme.
     #[derivative(Default)]
     Theme(Var),
+    /// Use the success palette of the current theme, for positive actions.
+    Success,
+    /// Use the danger palette of the current theme, for destructive actions.
+    Danger,
     /// Implement the style yourself.
     Custom(Somewhere<dyn iced::widget::button::StyleSheet<Style = Theme>>),
 }
+
+/// Style used for [Svg][iced::widget::Svg] widgets, tinting them to match surrounding text.
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+pub enum Svg {
+    /// Tint with the text color of the current theme.
+    #[derivative(Default)]
+    Theme(Var),
+    /// Tint with the text color of a button using the passed style.
+    Button(Button),
+    /// Implement the style yourself.
+    Custom(Somewhere<dyn iced::widget::svg::StyleSheet<Style = Theme>>),
+}
+
+/// Style used for [Scrollable][iced::widget::Scrollable] widgets.
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+pub enum Scrollable {
+    /// Use the default style of the current theme.
+    #[derivative(Default)]
+    Theme(Var),
+    /// Use a palette based on contrast swapping what is foreground and background based on
+    /// theme
+    ContrastPalette(ContrastPalette),
+    /// Implement the style yourself.
+    Custom(Somewhere<dyn iced::widget::scrollable::StyleSheet<Style = Theme>>),
+}
+
+/// Style used for [`PickList`][iced::widget::PickList] widgets.
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+pub enum PickList {
+    /// Use the default style of the current theme.
+    #[derivative(Default)]
+    Theme(Var),
+    /// Use a palette based on contrast swapping what is foreground and background based on
+    /// theme
+    ContrastPalette(ContrastPalette),
+    /// Implement the style yourself, the menu of the pick list uses the passed [Menu] style.
+    Custom(
+        Somewhere<dyn iced::widget::pick_list::StyleSheet<Style = Theme>>,
+        Box<Menu>,
+    ),
+}
+
+/// Style used for the menu [overlay][iced::overlay::menu] of [`PickList`][iced::widget::PickList]
+/// widgets.
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+pub enum Menu {
+    /// Use the default style of the current theme.
+    #[derivative(Default)]
+    Theme(Var),
+    /// Use a palette based on contrast swapping what is foreground and background based on
+    /// theme
+    ContrastPalette(ContrastPalette),
+    /// Implement the style yourself.
+    Custom(Somewhere<dyn iced::overlay::menu::StyleSheet<Style = Theme>>),
+}
+
+impl From<PickList> for Menu {
+    fn from(value: PickList) -> Self {
+        match value {
+            PickList::Theme(var) => Menu::Theme(var),
+            PickList::ContrastPalette(palette) => Menu::ContrastPalette(palette),
+            PickList::Custom(_, menu) => *menu,
+        }
+    }
+}
+
+/// Style used for [`TextInput`][iced::widget::TextInput] widgets.
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+pub enum TextInput {
+    /// Use the default style of the current theme.
+    #[derivative(Default)]
+    Theme(Var),
+    /// Use a palette based on contrast swapping what is foreground and background based on
+    /// theme
+    ContrastPalette(ContrastPalette),
+    /// Implement the style yourself.
+    Custom(Somewhere<dyn iced::widget::text_input::StyleSheet<Style = Theme>>),
+}
+
+/// Style used for [Checkbox][iced::widget::Checkbox] widgets.
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+pub enum Checkbox {
+    /// Use the default style of the current theme.
+    #[derivative(Default)]
+    Theme(Var),
+    /// Use a palette based on contrast swapping what is foreground and background based on
+    /// theme
+    ContrastPalette(ContrastPalette),
+    /// Implement the style yourself.
+    Custom(Somewhere<dyn iced::widget::checkbox::StyleSheet<Style = Theme>>),
+}
+
+/// Style used for [Slider][iced::widget::Slider] widgets.
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+pub enum Slider {
+    /// Use the default style of the current theme.
+    #[derivative(Default)]
+    Theme(Var),
+    /// Use a palette based on contrast swapping what is foreground and background based on
+    /// theme
+    ContrastPalette(ContrastPalette),
+    /// Implement the style yourself.
+    Custom(Somewhere<dyn iced::widget::slider::StyleSheet<Style = Theme>>),
+}
+
+/// Style used for [`ProgressBar`][iced::widget::ProgressBar] widgets.
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+pub enum ProgressBar {
+    /// Use the default style of the current theme.
+    #[derivative(Default)]
+    Theme(Var),
+    /// Use a palette based on contrast swapping what is foreground and background based on
+    /// theme
+    ContrastPalette(ContrastPalette),
+    /// Implement the style yourself.
+    Custom(Somewhere<dyn iced::widget::progress_bar::StyleSheet<Style = Theme>>),
+}
+
+/// Style used for [Radio][iced::widget::Radio] widgets.
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+pub enum Radio {
+    /// Use the default style of the current theme.
+    #[derivative(Default)]
+    Theme(Var),
+    /// Use a palette based on contrast swapping what is foreground and background based on
+    /// theme
+    ContrastPalette(ContrastPalette),
+    /// Implement the style yourself.
+    Custom(Somewhere<dyn iced::widget::radio::StyleSheet<Style = Theme>>),
+}
+
+/// Style used for [Rule][iced::widget::Rule] widgets.
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+pub enum Rule {
+    /// Use the default style of the current theme.
+    #[derivative(Default)]
+    Theme(Var),
+    /// Use a palette based on contrast swapping what is foreground and background based on
+    /// theme
+    ContrastPalette(ContrastPalette),
+    /// Implement the style yourself.
+    Custom(Somewhere<dyn iced::widget::rule::StyleSheet<Style = Theme>>),
+}
+
+/// Style used for [Modal][crate::modal::Modal] widgets.
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+pub enum Modal {
+    /// Use the default style of the current theme.
+    #[derivative(Default)]
+    Theme(Var),
+    /// Use a palette based on contrast swapping what is foreground and background based on
+    /// theme, muted for [`Var::Std`] and as is for [`Var::Alt`].
+    ContrastPalette(ContrastPalette, Var),
+    /// Implement the style yourself.
+    Custom(Somewhere<dyn crate::modal::StyleSheet<Style = Theme>>),
+}
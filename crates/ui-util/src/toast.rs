@@ -0,0 +1,402 @@
+//! Toast notifications shown over other content, expiring after a while.
+
+use crate::{
+    theme::{self, Var},
+    IteratorWidgetExt, Renderer,
+};
+use iced::{
+    widget::{button, container, text, Row},
+    Alignment, Command, Element, Length,
+};
+use iced_native::{
+    event, layout, mouse, overlay, renderer,
+    widget::{Operation, Tree},
+    Clipboard, Event, Layout, Point, Rectangle, Shell, Size, Widget,
+};
+use std::{collections::VecDeque, time::Duration};
+use tap::Pipe;
+use tokio::time;
+
+/// Width of shown toasts.
+const TOAST_WIDTH: u16 = 320;
+
+/// Distance between toasts and the edges of the content they are shown over.
+const MARGIN: f32 = 12.0;
+
+/// How severe what a [`Toast`] notifies of is, deciding its style and how long it is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Severity {
+    /// General information.
+    #[default]
+    Info,
+    /// Something succeeded.
+    Success,
+    /// Something failed.
+    Error,
+}
+
+impl Severity {
+    /// Get how long toasts of the severity are shown.
+    #[must_use]
+    pub fn duration(self) -> Duration {
+        match self {
+            Severity::Info | Severity::Success => Duration::from_secs(4),
+            Severity::Error => Duration::from_secs(8),
+        }
+    }
+
+    /// Get the container style of toasts of the severity.
+    fn style(self) -> theme::Container {
+        match self {
+            Severity::Info => theme::Container::Theme(Var::Alt),
+            Severity::Success => theme::Container::Success,
+            Severity::Error => theme::Container::Danger,
+        }
+    }
+}
+
+/// A notification shown by [`Toasts`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Toast {
+    /// Severity of the toast.
+    pub severity: Severity,
+    /// Message of the toast.
+    pub message: String,
+}
+
+impl Toast {
+    /// Create a new [`Toast`] with given severity and message.
+    #[must_use]
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+        }
+    }
+
+    /// Create a new [`Severity::Info`] [`Toast`].
+    #[must_use]
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(Severity::Info, message)
+    }
+
+    /// Create a new [`Severity::Success`] [`Toast`].
+    #[must_use]
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(Severity::Success, message)
+    }
+
+    /// Create a new [`Severity::Error`] [`Toast`].
+    #[must_use]
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, message)
+    }
+}
+
+/// A queue of [`Toast`] values identified by ids, shown oldest first in the bottom right corner
+/// of some content.
+#[derive(Debug, Default)]
+pub struct Toasts {
+    queue: VecDeque<(u64, Toast)>,
+    next_id: u64,
+}
+
+impl Toasts {
+    /// Most toasts shown at once, the oldest are dropped when more are pushed.
+    pub const MAX: usize = 5;
+
+    /// Push a toast, returning a command producing on expire with its id once the duration of
+    /// its severity has passed, which should be passed to [`Toasts::dismiss`].
+    pub fn push<Message>(&mut self, toast: Toast, on_expire: fn(u64) -> Message) -> Command<Message>
+    where
+        Message: 'static + Send,
+    {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let duration = toast.severity.duration();
+        self.queue.push_back((id, toast));
+        while self.queue.len() > Self::MAX {
+            self.queue.pop_front();
+        }
+
+        Command::perform(time::sleep(duration), move |()| on_expire(id))
+    }
+
+    /// Remove the toast with the given id, if it is still queued.
+    pub fn dismiss(&mut self, id: u64) {
+        self.queue.retain(|(queued, _)| *queued != id);
+    }
+
+    /// Check if there are no queued toasts.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// View the queued toasts over base content, where on dismiss receives the id of a toast
+    /// closed by the user.
+    pub fn view<'a, Message>(
+        &'a self,
+        base: impl Into<Element<'a, Message, Renderer>>,
+        on_dismiss: impl 'a + Fn(u64) -> Message,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+    {
+        let toasts = (!self.is_empty()).then(|| {
+            self.queue
+                .iter()
+                .collect_column(|(id, toast)| {
+                    Row::new()
+                        .push(text(&toast.message).width(Length::Fill))
+                        .push(
+                            text("x")
+                                .pipe(button)
+                                .padding([0, 6])
+                                .style(theme::Button::Theme(Var::Alt))
+                                .on_press(*id),
+                        )
+                        .spacing(6)
+                        .align_items(Alignment::Center)
+                        .pipe(container)
+                        .width(Length::Fill)
+                        .padding(6)
+                        .style(toast.severity.style())
+                })
+                .spacing(6)
+                .width(Length::Units(TOAST_WIDTH))
+                .pipe(Element::from)
+                .map(on_dismiss)
+        });
+
+        Stack {
+            children: [Some(base.into()), toasts].into_iter().flatten().collect(),
+        }
+        .into()
+    }
+}
+
+/// A widget showing its base content, and an element floating in its bottom right corner if
+/// there is one.
+struct Stack<'a, Message, Renderer> {
+    /// The base content followed by the floating element, if there is one.
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Stack<'_, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    fn width(&self) -> Length {
+        self.children[0].as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.children[0].as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.children[0].as_widget().layout(renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.children[0].as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.children[0]
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.children[0].as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children[0].as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        match (&mut self.children[..], &mut state.children[..]) {
+            // Overlays of the base, such as open menus, are shown in favour of the floating
+            // element, as only one overlay can be shown.
+            ([base, floating], [base_tree, floating_tree]) => base
+                .as_widget_mut()
+                .overlay(base_tree, layout, renderer)
+                .or_else(|| {
+                    Some(overlay::Element::new(
+                        layout.position(),
+                        Box::new(Floating {
+                            content: floating,
+                            tree: floating_tree,
+                            size: layout.bounds().size(),
+                        }),
+                    ))
+                }),
+            ([base, ..], [tree, ..]) => base.as_widget_mut().overlay(tree, layout, renderer),
+            _ => None,
+        }
+    }
+}
+
+/// Overlay of a [`Stack`] placing its floating element.
+struct Floating<'a, 'b, Message, Renderer> {
+    content: &'b mut Element<'a, Message, Renderer>,
+    tree: &'b mut Tree,
+    size: Size,
+}
+
+impl<Message, Renderer> overlay::Overlay<Message, Renderer> for Floating<'_, '_, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    fn layout(&self, renderer: &Renderer, _bounds: Size, position: Point) -> layout::Node {
+        let limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(
+                (self.size.width - 2.0 * MARGIN).max(0.0),
+                (self.size.height - 2.0 * MARGIN).max(0.0),
+            ),
+        );
+        let mut node = self.content.as_widget().layout(renderer, &limits);
+        let size = node.size();
+        node.move_to(Point::new(
+            position.x + self.size.width - size.width - MARGIN,
+            position.y + self.size.height - size.height - MARGIN,
+        ));
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        self.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            &layout.bounds(),
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            self.tree,
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            self.tree,
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Stack<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_native::Renderer,
+{
+    fn from(value: Stack<'a, Message, Renderer>) -> Self {
+        Element::new(value)
+    }
+}
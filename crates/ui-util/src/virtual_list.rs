@@ -0,0 +1,147 @@
+//! Module for [`VirtualList`] widget builder.
+
+use crate::Renderer;
+use bookmark_util::AnyWithExt;
+use iced::{
+    widget::{container, scrollable, Column, Scrollable, Space},
+    Element, Length,
+};
+use std::marker::PhantomData;
+use tap::Pipe;
+
+/// A widget for a scrollable list of equally tall rows where only the rows in view are built.
+pub struct VirtualList<'a, 'b, Item, View, OnScroll, Message> {
+    _lifetime: PhantomData<&'a Message>,
+    items: &'b [Item],
+    view: View,
+    on_scroll: OnScroll,
+    offset: f32,
+    row_height: u16,
+    viewport_height: u32,
+    overscan: usize,
+    id: Option<scrollable::Id>,
+}
+
+impl<'a, 'b, Item, View, OnScroll, Message> VirtualList<'a, 'b, Item, View, OnScroll, Message> {
+    /// Construct a new [`VirtualList`] over items, where view builds the element of an item and
+    /// on scroll receives the new relative offset of the list.
+    pub fn new(items: &'b [Item], offset: f32, view: View, on_scroll: OnScroll) -> Self
+    where
+        View: FnMut(&'b Item) -> Element<'a, Message, Renderer>,
+        OnScroll: 'a + Fn(f32) -> Message,
+    {
+        Self {
+            _lifetime: PhantomData,
+            items,
+            view,
+            on_scroll,
+            offset,
+            row_height: 24,
+            viewport_height: 768,
+            overscan: 8,
+            id: None,
+        }
+    }
+
+    /// Sets the height of every row.
+    #[must_use]
+    pub fn row_height(self, row_height: u16) -> Self {
+        Self { row_height, ..self }
+    }
+
+    /// Sets the height of the visible area, used to determine which rows are built.
+    #[must_use]
+    pub fn viewport_height(self, viewport_height: u32) -> Self {
+        Self {
+            viewport_height,
+            ..self
+        }
+    }
+
+    /// Sets the amount of rows built above and below the visible area.
+    #[must_use]
+    pub fn overscan(self, overscan: usize) -> Self {
+        Self { overscan, ..self }
+    }
+
+    /// Sets the id of the underlying [`Scrollable`][iced::widget::Scrollable].
+    #[must_use]
+    pub fn id(self, id: scrollable::Id) -> Self {
+        Self {
+            id: Some(id),
+            ..self
+        }
+    }
+
+    /// Get the first and one past the last index of the items in view, including overscan.
+    #[must_use]
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn visible(&self) -> (usize, usize) {
+        let row_height = f32::from(self.row_height.max(1));
+        let viewport_height = self.viewport_height as f32;
+        let total = self.items.len() as f32 * row_height;
+        let top = self.offset.clamp(0.0, 1.0) * (total - viewport_height).max(0.0);
+
+        let first = ((top / row_height) as usize).saturating_sub(self.overscan);
+        let count = (viewport_height / row_height).ceil() as usize + 2 * self.overscan;
+
+        (first, (first + count).min(self.items.len()))
+    }
+}
+
+/// Build a vertical space as tall as the given amount of rows, split up since [`Length::Units`]
+/// is limited to [`u16`].
+fn spacer<'a, Message: 'a>(rows: usize, row_height: u16) -> Column<'a, Message, Renderer> {
+    let mut column = Column::new();
+    let mut height = rows.saturating_mul(usize::from(row_height));
+    while height > 0 {
+        let chunk = u16::try_from(height).unwrap_or(u16::MAX);
+        height -= usize::from(chunk);
+        column = column.push(Space::with_height(Length::Units(chunk)));
+    }
+    column
+}
+
+impl<'a, 'b, Item, View, OnScroll, Message> From<VirtualList<'a, 'b, Item, View, OnScroll, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    View: FnMut(&'b Item) -> Element<'a, Message, Renderer>,
+    OnScroll: 'a + Fn(f32) -> Message,
+{
+    fn from(value: VirtualList<'a, 'b, Item, View, OnScroll, Message>) -> Self {
+        let (first, last) = value.visible();
+        let VirtualList {
+            items,
+            mut view,
+            on_scroll,
+            row_height,
+            id,
+            ..
+        } = value;
+
+        items[first..last]
+            .iter()
+            .fold(
+                Column::new().push(spacer(first, row_height)),
+                |column, item| {
+                    column.push(
+                        view(item)
+                            .pipe(container)
+                            .width(Length::Fill)
+                            .height(Length::Units(row_height)),
+                    )
+                },
+            )
+            .push(spacer(items.len() - last, row_height))
+            .width(Length::Fill)
+            .pipe(scrollable)
+            .on_scroll(move |offset| on_scroll(offset.y))
+            .with(id, Scrollable::id)
+            .into()
+    }
+}
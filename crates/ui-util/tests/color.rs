@@ -0,0 +1,91 @@
+//! Tests for color space conversions and perceptual color operations.
+
+use bookmark_ui_util::color::{AccentPalette, ColorManipExt, ContrastLevel, Hsl, LinearRgb, Oklab};
+use iced::Color;
+
+const COLORS: [Color; 4] = [
+    Color::WHITE,
+    Color::BLACK,
+    Color {
+        r: 0.2,
+        g: 0.5,
+        b: 0.9,
+        a: 1.0,
+    },
+    Color {
+        r: 0.9,
+        g: 0.1,
+        b: 0.1,
+        a: 0.5,
+    },
+];
+
+fn assert_close(a: Color, b: Color) {
+    let close = [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)]
+        .into_iter()
+        .all(|(a, b)| (a - b).abs() < 0.001);
+    assert!(close, "{a:?} is not close to {b:?}");
+}
+
+#[test]
+fn round_trip() {
+    for color in COLORS {
+        assert_close(Color::from(LinearRgb::from(color)), color);
+        assert_close(Color::from(Hsl::from(color)), color);
+        assert_close(Color::from(Oklab::from(color)), color);
+    }
+}
+
+#[test]
+fn oklab_lightness() {
+    assert!((Oklab::from(Color::WHITE).l - 1.0).abs() < 0.001);
+    assert!(Oklab::from(Color::BLACK).l.abs() < 0.001);
+}
+
+#[test]
+fn lighten_darken() {
+    let grey = Color::from_rgb(0.5, 0.5, 0.5);
+    assert_close(grey.lighten(1.0), Color::WHITE);
+    assert_close(grey.darken(1.0), Color::BLACK);
+
+    let color = COLORS[2];
+    assert!(color.lighten(0.5).luminance() > color.luminance());
+    assert!(color.darken(0.5).luminance() < color.luminance());
+}
+
+#[test]
+fn saturate() {
+    let color = COLORS[2];
+    assert!(Oklab::from(color.saturate(-1.0)).chroma() < 0.001);
+    assert!(Oklab::from(color.saturate(-0.5)).chroma() < Oklab::from(color).chroma());
+}
+
+#[test]
+fn mute_keeps_alpha() {
+    let color = COLORS[3];
+    assert!((color.mute(Some(1.0)).a - color.a).abs() < f32::EPSILON);
+    assert_close(
+        color.mute(Some(1.0)),
+        Color {
+            r: 0.5,
+            g: 0.5,
+            b: 0.5,
+            a: color.a,
+        },
+    );
+}
+
+#[test]
+fn accent_palette_contrast() {
+    for accent in COLORS {
+        for mute in [0.0, 0.25, 1.0] {
+            let generated = AccentPalette::new(accent, mute);
+            assert!(generated.mute <= mute);
+            for palette in [generated.light(), generated.dark(), generated.dark_mute()] {
+                if let Err(issues) = palette.validate(ContrastLevel::AA) {
+                    panic!("{accent:?} with mute {mute} fails AA: {issues:?}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Tests for reading the desktop color scheme against a local stand-in for the settings portal.
+
+use bookmark_ui_util::color_scheme::{self, ColorScheme};
+use iced::futures::{try_join, StreamExt};
+use tokio::net::UnixStream;
+use zbus::{
+    dbus_interface,
+    zvariant::{OwnedValue, Value},
+    Connection, ConnectionBuilder, Guid, SignalContext,
+};
+
+const PATH: &str = "/org/freedesktop/portal/desktop";
+
+/// Stand-in for the settings interface of the desktop portal.
+struct Portal {
+    color_scheme: u32,
+}
+
+#[dbus_interface(name = "org.freedesktop.portal.Settings")]
+impl Portal {
+    fn read(&self, namespace: &str, key: &str) -> zbus::fdo::Result<OwnedValue> {
+        if namespace == "org.freedesktop.appearance" && key == "color-scheme" {
+            Ok(Value::new(Value::new(self.color_scheme)).into())
+        } else {
+            Err(zbus::fdo::Error::Failed(format!(
+                "no setting {namespace} {key}"
+            )))
+        }
+    }
+
+    #[dbus_interface(signal)]
+    async fn setting_changed(
+        context: &SignalContext<'_>,
+        namespace: &str,
+        key: &str,
+        value: Value<'_>,
+    ) -> zbus::Result<()>;
+}
+
+/// Connect a client to a stand-in portal over a socket pair.
+async fn connect(color_scheme: u32) -> zbus::Result<(Connection, Connection)> {
+    let guid = Guid::generate();
+    let (server, client) = UnixStream::pair()?;
+    let server = ConnectionBuilder::unix_stream(server)
+        .server(&guid)
+        .p2p()
+        .serve_at(PATH, Portal { color_scheme })?
+        .build();
+    let client = ConnectionBuilder::unix_stream(client).p2p().build();
+    try_join!(server, client)
+}
+
+#[tokio::test]
+async fn read_dark() -> zbus::Result<()> {
+    let (_server, client) = connect(1).await?;
+    assert_eq!(color_scheme::read_with(&client).await?, ColorScheme::Dark);
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_light() -> zbus::Result<()> {
+    let (_server, client) = connect(2).await?;
+    assert_eq!(color_scheme::read_with(&client).await?, ColorScheme::Light);
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_no_preference() -> zbus::Result<()> {
+    let (_server, client) = connect(0).await?;
+    assert_eq!(
+        color_scheme::read_with(&client).await?,
+        ColorScheme::NoPreference
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn changes() -> zbus::Result<()> {
+    let (server, client) = connect(2).await?;
+    let mut changes = color_scheme::changes_with(&client).await?;
+
+    let context = SignalContext::new(&server, PATH)?;
+    Portal::setting_changed(
+        &context,
+        "org.freedesktop.appearance",
+        "accent-color",
+        Value::new(1u32),
+    )
+    .await?;
+    Portal::setting_changed(
+        &context,
+        "org.freedesktop.appearance",
+        "color-scheme",
+        Value::new(1u32),
+    )
+    .await?;
+
+    assert_eq!(changes.next().await, Some(ColorScheme::Dark));
+    Ok(())
+}
+
+#[test]
+fn gtk_theme() {
+    assert_eq!(ColorScheme::from_gtk_theme(""), ColorScheme::NoPreference);
+    assert_eq!(ColorScheme::from_gtk_theme("Adwaita"), ColorScheme::Light);
+    assert_eq!(
+        ColorScheme::from_gtk_theme("Adwaita:dark"),
+        ColorScheme::Dark
+    );
+    assert_eq!(
+        ColorScheme::from_gtk_theme("Adwaita-Dark"),
+        ColorScheme::Dark
+    );
+}
+
+#[test]
+fn known_preference_ignores_env() {
+    assert_eq!(ColorScheme::Dark.or_env(), ColorScheme::Dark);
+    assert_eq!(ColorScheme::Light.or_env(), ColorScheme::Light);
+}
@@ -0,0 +1,73 @@
+//! Tests for contrast checking of palettes.
+
+use bookmark_ui_util::{
+    color::{ColorManipExt, ColorPair, ContrastIssue, ContrastLevel, ContrastPalette, PaletteRole},
+    Theme,
+};
+use iced::Color;
+
+fn assert_close(a: f32, b: f32) {
+    assert!((a - b).abs() < 0.01, "{a} is not close to {b}");
+}
+
+#[test]
+fn luminance() {
+    assert_close(Color::WHITE.luminance(), 1.0);
+    assert_close(Color::BLACK.luminance(), 0.0);
+    assert_close(Color::from_rgb(0.5, 0.5, 0.5).luminance(), 0.214);
+}
+
+#[test]
+fn contrast_ratio() {
+    assert_close(Color::WHITE.contrast_ratio(Color::BLACK), 21.0);
+    assert_close(Color::BLACK.contrast_ratio(Color::WHITE), 21.0);
+    assert_close(Color::WHITE.contrast_ratio(Color::WHITE), 1.0);
+}
+
+#[test]
+fn builtin_themes_pass_aa() {
+    for theme in Theme::ALL {
+        if let Err(issues) = theme.theme_palette().validate(ContrastLevel::AA) {
+            panic!("{theme} fails AA: {issues:?}");
+        }
+    }
+}
+
+#[test]
+fn light_and_dark_pass_aaa() {
+    for theme in [Theme::Light, Theme::Dark] {
+        if let Err(issues) = theme.theme_palette().validate(ContrastLevel::AAA) {
+            panic!("{theme} fails AAA: {issues:?}");
+        }
+    }
+}
+
+#[test]
+fn muted_palette_fails() {
+    let palette = ContrastPalette::monochrome();
+    let muted = ContrastPalette {
+        bright: palette.bright.mute(Some(0.9)),
+        dim: palette.dim.mute(Some(0.9)),
+    };
+    let issues = Theme::Light
+        .convert_palette(muted)
+        .contrast_issues(ContrastLevel::AA);
+    assert_eq!(
+        issues.iter().map(|&(pair, ..)| pair).collect::<Vec<_>>(),
+        [ColorPair::Text, ColorPair::Foreground],
+    );
+}
+
+#[test]
+fn issue_names_palette() {
+    let issue = ContrastIssue {
+        role: PaletteRole::AltHighlight,
+        pair: ColorPair::Text,
+        ratio: 2.5,
+        required: 4.5,
+    };
+    assert_eq!(
+        issue.to_string(),
+        "alt highlight text has a contrast ratio of 2.50:1, needs 4.5:1"
+    );
+}
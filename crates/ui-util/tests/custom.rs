@@ -0,0 +1,44 @@
+//! Tests for parsing user defined themes.
+
+use bookmark_ui_util::custom::{Base, CustomTheme};
+
+#[test]
+fn accent_generates_palettes() {
+    let theme: CustomTheme = toml::from_str(
+        r##"
+        name = "accent"
+        base = "dark_mute"
+        accent = "#3366cc"
+        mute = 1.0
+        "##,
+    )
+    .expect("theme should parse");
+    assert_eq!(theme.base, Base::DarkMute);
+    assert!(theme.mute < 1.0);
+}
+
+#[test]
+fn accent_keeps_mute_of_given_palettes() {
+    let theme: CustomTheme = toml::from_str(
+        r##"
+        name = "palettes"
+        accent = "#3366cc"
+        mute = 0.5
+
+        [palette]
+        bright = "#ffffff"
+        dim = "#000000"
+
+        [palette_alt]
+        bright = "#eeeeee"
+        dim = "#111111"
+        "##,
+    )
+    .expect("theme should parse");
+    assert!((theme.mute - 0.5).abs() < f32::EPSILON);
+}
+
+#[test]
+fn palettes_or_accent_needed() {
+    assert!(toml::from_str::<CustomTheme>(r#"name = "empty""#).is_err());
+}
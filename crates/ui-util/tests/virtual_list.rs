@@ -0,0 +1,38 @@
+//! Tests for which rows of a virtual list are built.
+
+use bookmark_ui_util::virtual_list::VirtualList;
+use iced::{widget::Space, Length};
+
+fn visible(items: &[usize], offset: f32) -> (usize, usize) {
+    VirtualList::new(
+        items,
+        offset,
+        |_| Space::with_height(Length::Fill).into(),
+        |_| (),
+    )
+    .row_height(10)
+    .viewport_height(100)
+    .overscan(2)
+    .visible()
+}
+
+#[test]
+fn visible_follows_offset() {
+    let items = (0..100).collect::<Vec<_>>();
+    assert_eq!(visible(&items, 0.5), (43, 57));
+    assert_eq!(visible(&items, 1.0), (88, 100));
+}
+
+#[test]
+fn reset_offset_shows_first_rows() {
+    let items = (0..100).collect::<Vec<_>>();
+    assert_eq!(visible(&items, 0.0), (0, 14));
+    assert_eq!(visible(&items, -1.0), visible(&items, 0.0));
+    assert_eq!(visible(&items, 2.0), visible(&items, 1.0));
+}
+
+#[test]
+fn visible_fits_short_lists() {
+    assert_eq!(visible(&[], 0.5), (0, 0));
+    assert_eq!(visible(&[0, 1, 2], 0.5), (0, 3));
+}